@@ -0,0 +1,244 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+
+use clap::parser::ValueSource;
+use clap::ArgMatches;
+use serde::Deserialize;
+use typestorm::render::TypedLetters;
+use typestorm::theme::{Theme, THEME_NAMES};
+use typestorm::words_generator::SamplingMode;
+use typestorm::words_lists::WordsList;
+
+use crate::output::OutputFormat;
+use crate::Args;
+
+/// Default settings from `$XDG_CONFIG_HOME/typestorm/config.toml`. Keys are named like
+/// command line flags, e.g. `timeframe = 30` or `words-list = "english1k"`.
+/// Own themes are defined in `[themes.<name>]` tables with `text`, `correct` and `wrong` styles,
+/// e.g. `correct = { foreground = "#56b4e9", bold = true }`.
+#[derive(Deserialize, Default, Debug)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct Config {
+    timeframe: Option<u64>,
+    words: Option<u64>,
+    min: Option<usize>,
+    max: Option<usize>,
+    words_list: Option<WordsList>,
+    words_file: Option<PathBuf>,
+    margin: Option<usize>,
+    sampling: Option<SamplingMode>,
+    top: Option<u64>,
+    output: Option<OutputFormat>,
+    skip_on_space: Option<bool>,
+    typed_letters: Option<TypedLetters>,
+    theme: Option<String>,
+    #[serde(default)]
+    themes: BTreeMap<String, Theme>,
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Unreadable(PathBuf, std::io::Error),
+    Invalid(PathBuf, toml::de::Error),
+    InvalidValue(PathBuf, &'static str, &'static str),
+    UnknownTheme(String, Vec<String>),
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::Unreadable(path, err) => {
+                write!(f, "Can't read config {}: {}", path.display(), err)
+            }
+            ConfigError::Invalid(path, err) => {
+                write!(f, "Invalid config {}: {}", path.display(), err)
+            }
+            ConfigError::InvalidValue(path, key, reason) => {
+                write!(f, "Invalid config {}: `{}` {}", path.display(), key, reason)
+            }
+            ConfigError::UnknownTheme(name, available) => {
+                write!(f, "Unknown theme `{}`, available themes: {}", name, available.join(", "))
+            }
+        }
+    }
+}
+
+pub fn get_config_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|it| it.join("typestorm").join("config.toml"))
+}
+
+/// Returns default config if there is no config file
+pub fn load_config() -> Result<Config, ConfigError> {
+    let path = match get_config_path() {
+        Some(path) if path.exists() => path,
+        _ => return Ok(Config::default()),
+    };
+    let content =
+        std::fs::read_to_string(&path).map_err(|err| ConfigError::Unreadable(path.clone(), err))?;
+    parse_config(path, &content)
+}
+
+/// Checks values and keys that can't be used together, like the command line does
+fn parse_config(path: PathBuf, content: &str) -> Result<Config, ConfigError> {
+    let config: Config =
+        toml::from_str(content).map_err(|err| ConfigError::Invalid(path.clone(), err))?;
+    if config.timeframe == Some(0) {
+        return Err(ConfigError::InvalidValue(path, "timeframe", "must be at least 1"));
+    }
+    if config.timeframe.is_some() && config.words.is_some() {
+        return Err(ConfigError::InvalidValue(path, "words", "can't be used with `timeframe`"));
+    }
+    if config.words_list.is_some() && config.words_file.is_some() {
+        let reason = "can't be used with `words-list`";
+        return Err(ConfigError::InvalidValue(path, "words-file", reason));
+    }
+    if config.words == Some(0) {
+        return Err(ConfigError::InvalidValue(path, "words", "must be at least 1"));
+    }
+    if config.top == Some(0) {
+        return Err(ConfigError::InvalidValue(path, "top", "must be at least 1"));
+    }
+    if config.min.zip(config.max).is_some_and(|(min, max)| min >= max) {
+        return Err(ConfigError::InvalidValue(path, "min", "must be smaller than `max`"));
+    }
+    Ok(config)
+}
+
+fn is_from_command_line(matches: &ArgMatches, id: &str) -> bool {
+    matches.value_source(id) == Some(ValueSource::CommandLine)
+}
+
+impl Config {
+    /// Fills in everything that wasn't passed on the command line.
+    /// Config never changes daily challenge and doesn't mix with conflicting flags.
+    pub fn apply(&self, args: &mut Args, matches: &ArgMatches) {
+        let cli = |id| is_from_command_line(matches, id);
+        let choose_words = !args.daily && !args.quote;
+        let choose_mode = choose_words && !cli("timeframe") && !cli("words_count");
+        let choose_list = choose_words && !cli("words_list") && !cli("words_file");
+
+        if let Some(timeframe) = self.timeframe.filter(|_| !args.daily && !cli("timeframe")) {
+            args.timeframe = timeframe;
+        }
+        if let Some(words) = self.words.filter(|_| choose_mode) {
+            args.words_count = Some(words);
+        }
+        if let Some(words_list) = self.words_list.clone().filter(|_| choose_list) {
+            args.words_list = words_list;
+        }
+        if let Some(words_file) = self.words_file.clone().filter(|_| choose_list) {
+            args.words_file = Some(words_file);
+        }
+        if let Some(min) = self.min.filter(|_| choose_words && !cli("min_word_length")) {
+            args.min_word_length = min;
+        }
+        if let Some(max) = self.max.filter(|_| choose_words && !cli("max_word_length")) {
+            args.max_word_length = max;
+        }
+        if let Some(sampling) = self.sampling.filter(|_| choose_words && !cli("sampling")) {
+            args.sampling = sampling;
+        }
+        if let Some(top) = self.top.filter(|_| choose_words && !cli("top")) {
+            args.top = Some(top);
+        }
+        if let Some(margin) = self.margin.filter(|_| !cli("margin")) {
+            args.margin = margin;
+        }
+        if let Some(output) = self.output.filter(|_| !cli("output")) {
+            args.output = output;
+        }
+        // Skipping changes how results are scored, so daily attempts are all played without it
+        let choose_skip = !args.daily && !cli("skip_on_space");
+        if let Some(skip_on_space) = self.skip_on_space.filter(|_| choose_skip) {
+            args.skip_on_space = skip_on_space;
+        }
+        if let Some(typed_letters) = self.typed_letters.filter(|_| !cli("typed_letters")) {
+            args.typed_letters = typed_letters;
+        }
+        if let Some(theme) = self.theme.clone().filter(|_| !cli("theme")) {
+            args.theme = theme;
+        }
+    }
+    /// Themes from config file can replace built-in ones with the same name
+    pub fn get_theme(&self, name: &str) -> Result<Theme, ConfigError> {
+        if let Some(theme) = self.themes.get(name) {
+            return Ok(theme.clone());
+        }
+        Theme::get_builtin(name).ok_or_else(|| {
+            let mut available = THEME_NAMES.map(String::from).to_vec();
+            let is_own = |name: &&String| !THEME_NAMES.contains(&name.as_str());
+            available.extend(self.themes.keys().filter(is_own).cloned());
+            ConfigError::UnknownTheme(name.to_string(), available)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::{CommandFactory, FromArgMatches};
+    use typestorm::theme::Color;
+
+    use super::*;
+
+    fn apply(config: &str, cli: &[&str]) -> Args {
+        let config: Config = toml::from_str(config).unwrap();
+        let matches = Args::command().get_matches_from(cli);
+        let mut args = Args::from_arg_matches(&matches).unwrap();
+        config.apply(&mut args, &matches);
+        args
+    }
+
+    #[test]
+    fn command_line_takes_precedence() {
+        let config = "timeframe = 30\nmargin = 2\noutput = \"json\"";
+        let args = apply(config, &["typestorm", "-t", "15"]);
+        assert_eq!(args.timeframe, 15);
+        assert_eq!(args.margin, 2);
+        assert_eq!(args.output, OutputFormat::Json);
+    }
+
+    #[test]
+    fn conflicting_config_values_are_skipped() {
+        let args = apply("words = 25", &["typestorm", "-t", "15"]);
+        assert_eq!(args.words_count, None);
+        let args = apply("words-list = \"german\"", &["typestorm", "--quote"]);
+        assert!(matches!(args.words_list, WordsList::English));
+        let args = apply("skip-on-space = true", &["typestorm", "--daily"]);
+        assert!(!args.skip_on_space);
+    }
+
+    #[test]
+    fn themes_are_taken_from_config_first() {
+        let config = "theme = \"mine\"\n[themes.mine]\nwrong = { foreground = \"yellow\" }";
+        let args = apply(config, &["typestorm"]);
+        assert_eq!(args.theme, "mine");
+        let config: Config = toml::from_str(config).unwrap();
+        let theme = config.get_theme("mine").unwrap();
+        assert_eq!(theme.wrong.foreground, Color::Indexed(3));
+        assert_eq!(theme.correct, Theme::default().correct);
+        assert!(config.get_theme("monochrome").is_ok());
+        assert!(matches!(config.get_theme("missing"), Err(ConfigError::UnknownTheme(..))));
+    }
+
+    #[test]
+    fn invalid_and_conflicting_values_are_rejected() {
+        let parse = |content| parse_config(PathBuf::from("config.toml"), content);
+        assert!(parse("timeframe = 30\nwords-list = \"german\"").is_ok());
+        for (content, key) in [
+            ("timeframe = 0", "timeframe"),
+            ("words = 0", "words"),
+            ("timeframe = 30\nwords = 25", "words"),
+            ("words-list = \"german\"\nwords-file = \"words.txt\"", "words-file"),
+        ] {
+            let err = parse(content).unwrap_err();
+            assert!(matches!(err, ConfigError::InvalidValue(_, it, _) if it == key), "{}", content);
+        }
+    }
+
+    #[test]
+    fn unknown_keys_are_rejected() {
+        let err = toml::from_str::<Config>("colour = \"red\"").unwrap_err();
+        assert!(err.to_string().contains("unknown field `colour`"));
+    }
+}
@@ -1,6 +1,4 @@
-use ncurses::{stdscr, wmove};
-
-#[derive(Default)]
+#[derive(Default, Debug)]
 pub struct CursorPosition {
     x: usize,
     line_position: usize,
@@ -22,11 +20,13 @@ impl CursorPosition {
     pub fn get_x(&self) -> usize {
         self.x
     }
-    pub fn move_left(&mut self) {
-        self.x -= 1;
+    /// Moves cursor left by display width of the letter
+    pub fn move_left(&mut self, width: usize) {
+        self.x = self.x.saturating_sub(width);
     }
-    pub fn move_right(&mut self) {
-        self.x += 1;
+    /// Moves cursor right by display width of the letter
+    pub fn move_right(&mut self, width: usize) {
+        self.x += width;
     }
     pub fn move_to_new_line(&mut self) {
         self.previous_line_x.push(self.x - 1);
@@ -36,9 +36,6 @@ impl CursorPosition {
     pub fn go_back_to_old_line(&mut self) {
         assert_ne!(self.line_position, 0);
         self.line_position -= 1;
-        self.x = self.previous_line_x[self.line_position];
-    }
-    pub fn display(&self) {
-        wmove(stdscr(), 0, self.x as i32);
+        self.x = self.previous_line_x.pop().unwrap();
     }
 }
@@ -0,0 +1,85 @@
+use chrono::{NaiveDate, Utc};
+use rand::seq::SliceRandom;
+
+use crate::history::HistoryEntry;
+use typestorm::words_generator::create_rng;
+use typestorm::words_lists::WordsList;
+
+const DAILY_WORDS_COUNTS: [u64; 3] = [25, 50, 100];
+
+/// Test settings shared by everyone on the same day
+pub struct DailyChallenge {
+    pub date: NaiveDate,
+    pub seed: u64,
+    pub words_count: u64,
+    pub words_list: WordsList,
+}
+
+/// Challenge changes at midnight UTC, so it's the same for everyone regardless of time zone
+pub fn get_today_challenge() -> DailyChallenge {
+    get_daily_challenge(Utc::now().date_naive())
+}
+
+pub fn get_daily_challenge(date: NaiveDate) -> DailyChallenge {
+    // e.g. 20261018
+    let seed = date.format("%Y%m%d").to_string().parse::<u64>().unwrap();
+    let mut rng = create_rng(Some(seed));
+    DailyChallenge {
+        date,
+        seed,
+        words_count: *DAILY_WORDS_COUNTS.choose(&mut rng).unwrap(),
+        words_list: [WordsList::English, WordsList::English1k]
+            .choose(&mut rng)
+            .unwrap()
+            .clone(),
+    }
+}
+
+pub fn print_today_best(challenge: &DailyChallenge, history: &[HistoryEntry]) {
+    let attempts = history
+        .iter()
+        .filter(|it| it.daily && it.timestamp.with_timezone(&Utc).date_naive() == challenge.date)
+        .collect::<Vec<&HistoryEntry>>();
+    let Some(best) = attempts.iter().max_by(|a, b| a.wpm.total_cmp(&b.wpm)) else {
+        return;
+    };
+    println!();
+    println!(
+        "Today's best ({}): {} WPM, {}% accuracy, {} attempt(s)",
+        challenge.date.format("%Y-%m-%d"),
+        best.wpm as i64,
+        best.accuracy as i64,
+        attempts.len()
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use typestorm::words_generator::{SamplingMode, WordsGenerator};
+    use typestorm::words_lists::get_words_list;
+
+    use super::*;
+
+    #[test]
+    fn challenge_is_the_same_for_the_same_day() {
+        let date = NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+        let challenge = get_daily_challenge(date);
+        for again in [get_daily_challenge(date), get_daily_challenge(date)] {
+            assert_eq!(again.seed, challenge.seed);
+            assert_eq!(again.words_count, challenge.words_count);
+            assert_eq!(again.words_list.info().words, challenge.words_list.info().words);
+        }
+        assert_eq!(challenge.seed, 20261018);
+        assert_eq!(challenge.words_count, 25);
+        assert!(matches!(challenge.words_list, WordsList::English));
+
+        let mut generator = WordsGenerator::new(
+            get_words_list(&challenge.words_list),
+            Some(challenge.words_count as usize),
+            SamplingMode::Uniform,
+            Some(challenge.seed),
+        );
+        let words = (0..6).map(|_| generator.next_word().unwrap().to_string()).collect::<Vec<_>>();
+        assert_eq!(words, ["go", "a", "on", "plan", "say", "most"]);
+    }
+}
@@ -1,12 +1,22 @@
 use crate::cursor_position::CursorPosition;
-use crate::words::Status::{Correct, Unmark, Wrong};
-use crate::words::Word;
+use crate::words::Status::{Correct, Extra, Missed, Unmark, Wrong};
+use crate::words::{Letter, Word};
+
+/// Extra letters after that are counted but not shown, so a word alone in a line can't get
+/// wider than the terminal
+pub const MAX_EXTRA_LETTERS: usize = 10;
 
 pub fn on_backspace(word: &mut Word, cursor: &mut CursorPosition) -> bool {
-    for letter in word.letters.iter_mut().rev() {
+    for (i, letter) in word.letters.iter_mut().enumerate().rev() {
+        if letter.status == Extra {
+            cursor.move_left(letter.width());
+            word.letters.remove(i);
+            return true;
+        }
         if letter.status == Correct || letter.status == Wrong {
             letter.status = Unmark;
-            cursor.move_left();
+            letter.typed = None;
+            cursor.move_left(letter.width());
             return true;
         }
     }
@@ -15,37 +25,81 @@ pub fn on_backspace(word: &mut Word, cursor: &mut CursorPosition) -> bool {
 pub fn on_keypress(
     word: &mut Word,
     c: char,
-    did_mark_letter: &mut bool,
     cursor: &mut CursorPosition,
     correctly_pressed_letters: &mut usize,
     all_letter_pressed: &mut usize,
-) -> bool {
-    if *did_mark_letter {
-        return true;
+    extra_letters_pressed: &mut usize,
+) {
+    let next = word.letters.iter().position(|it| it.status == Unmark);
+    let is_space_next = next
+        .is_some_and(|it| it + 1 == word.letters.len() && word.letters[it].current_letter == ' ');
+    if c != ' ' && is_space_next {
+        // Instead of marking the space wrong, letter is shown after the word until space is typed
+        *all_letter_pressed += 1;
+        *extra_letters_pressed += 1;
+        if word.letters.iter().filter(|it| it.status == Extra).count() < MAX_EXTRA_LETTERS {
+            let letter = Letter {
+                current_letter: c,
+                status: Extra,
+                typed: Some(c),
+            };
+            cursor.move_right(letter.width());
+            word.letters.insert(word.letters.len() - 1, letter);
+        }
+        return;
     }
     for letter in &mut word.letters {
         if letter.status == Unmark && letter.current_letter == c {
             letter.status = Correct;
-            *did_mark_letter = true;
+            letter.typed = Some(c);
             *all_letter_pressed += 1;
             *correctly_pressed_letters += 1;
+            cursor.move_right(letter.width());
             break;
         }
         if letter.status == Unmark && letter.current_letter != c {
             letter.status = Wrong;
+            letter.typed = Some(c);
             *all_letter_pressed += 1;
-            *did_mark_letter = true;
+            cursor.move_right(letter.width());
             break;
         }
     }
-    cursor.move_right();
 
-    if word
-        .letters
-        .iter()
-        .all(|it| it.status == Correct || it.status == Wrong)
-    {
+    if word.letters.iter().all(|it| it.status != Unmark) {
         word.completed = true;
     };
-    false
+}
+
+/// Submits the word: letters that weren't typed yet are missed and cursor jumps after the word.
+/// Space at the start of a word is ignored, so a double space doesn't skip a whole word.
+pub fn on_word_skip(
+    word: &mut Word,
+    cursor: &mut CursorPosition,
+    correctly_pressed_letters: &mut usize,
+    all_letter_pressed: &mut usize,
+) {
+    if word.letters.iter().all(|it| it.status == Unmark) {
+        return;
+    }
+    *all_letter_pressed += 1;
+    for letter in word.letters.iter_mut().filter(|it| it.status == Unmark) {
+        if letter.current_letter == ' ' {
+            letter.status = Correct;
+            letter.typed = Some(' ');
+            *correctly_pressed_letters += 1;
+        } else {
+            letter.status = Missed;
+        }
+        cursor.move_right(letter.width());
+    }
+    word.completed = true;
+}
+
+/// Brings back letters skipped with space and puts cursor after the last typed one
+pub fn undo_word_skip(word: &mut Word, cursor: &mut CursorPosition) {
+    for letter in word.letters.iter_mut().filter(|it| it.status == Missed) {
+        letter.status = Unmark;
+        cursor.move_left(letter.width());
+    }
 }
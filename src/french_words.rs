@@ -0,0 +1,188 @@
+pub static WORDS: [&str; 186] = [
+    "de",
+    "la",
+    "le",
+    "et",
+    "les",
+    "des",
+    "en",
+    "un",
+    "du",
+    "une",
+    "que",
+    "est",
+    "pour",
+    "qui",
+    "dans",
+    "par",
+    "plus",
+    "pas",
+    "au",
+    "sur",
+    "ne",
+    "se",
+    "ce",
+    "il",
+    "sont",
+    "avec",
+    "son",
+    "cette",
+    "aux",
+    "à",
+    "ont",
+    "ses",
+    "mais",
+    "comme",
+    "on",
+    "tout",
+    "nous",
+    "sa",
+    "fait",
+    "été",
+    "aussi",
+    "leur",
+    "bien",
+    "peut",
+    "ces",
+    "y",
+    "deux",
+    "ou",
+    "elle",
+    "si",
+    "entre",
+    "même",
+    "était",
+    "ans",
+    "dont",
+    "sans",
+    "très",
+    "nos",
+    "autres",
+    "encore",
+    "après",
+    "notre",
+    "moins",
+    "où",
+    "faire",
+    "leurs",
+    "lui",
+    "sous",
+    "ils",
+    "temps",
+    "depuis",
+    "être",
+    "avoir",
+    "dire",
+    "aller",
+    "voir",
+    "savoir",
+    "pouvoir",
+    "vouloir",
+    "venir",
+    "devoir",
+    "prendre",
+    "trouver",
+    "donner",
+    "falloir",
+    "parler",
+    "mettre",
+    "passer",
+    "regarder",
+    "aimer",
+    "croire",
+    "demander",
+    "rester",
+    "répondre",
+    "entendre",
+    "penser",
+    "arriver",
+    "connaître",
+    "devenir",
+    "sentir",
+    "sembler",
+    "tenir",
+    "comprendre",
+    "rendre",
+    "attendre",
+    "sortir",
+    "vivre",
+    "entrer",
+    "porter",
+    "chercher",
+    "revenir",
+    "appeler",
+    "mourir",
+    "partir",
+    "jeter",
+    "suivre",
+    "écrire",
+    "montrer",
+    "tomber",
+    "ouvrir",
+    "perdre",
+    "jour",
+    "homme",
+    "femme",
+    "vie",
+    "main",
+    "chose",
+    "monde",
+    "enfant",
+    "fois",
+    "œil",
+    "tête",
+    "ami",
+    "maison",
+    "nuit",
+    "porte",
+    "pays",
+    "père",
+    "mère",
+    "terre",
+    "heure",
+    "voix",
+    "eau",
+    "cœur",
+    "ville",
+    "place",
+    "année",
+    "moment",
+    "côté",
+    "travail",
+    "mot",
+    "nom",
+    "question",
+    "raison",
+    "idée",
+    "fin",
+    "guerre",
+    "histoire",
+    "grand",
+    "petit",
+    "bon",
+    "nouveau",
+    "premier",
+    "dernier",
+    "jeune",
+    "vieux",
+    "beau",
+    "long",
+    "seul",
+    "autre",
+    "vrai",
+    "blanc",
+    "noir",
+    "rouge",
+    "toujours",
+    "jamais",
+    "rien",
+    "quelque",
+    "peu",
+    "trop",
+    "beaucoup",
+    "déjà",
+    "alors",
+    "ici",
+    "là",
+    "maintenant",
+];
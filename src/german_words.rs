@@ -0,0 +1,200 @@
+pub static WORDS: [&str; 198] = [
+    "der",
+    "die",
+    "und",
+    "in",
+    "den",
+    "von",
+    "zu",
+    "das",
+    "mit",
+    "sich",
+    "des",
+    "auf",
+    "für",
+    "ist",
+    "im",
+    "dem",
+    "nicht",
+    "ein",
+    "eine",
+    "als",
+    "auch",
+    "es",
+    "an",
+    "werden",
+    "aus",
+    "er",
+    "hat",
+    "dass",
+    "sie",
+    "nach",
+    "wird",
+    "bei",
+    "einer",
+    "um",
+    "am",
+    "sind",
+    "noch",
+    "wie",
+    "einem",
+    "über",
+    "einen",
+    "so",
+    "zum",
+    "war",
+    "haben",
+    "nur",
+    "oder",
+    "aber",
+    "vor",
+    "zur",
+    "bis",
+    "mehr",
+    "durch",
+    "man",
+    "sein",
+    "wurde",
+    "sei",
+    "sagte",
+    "schon",
+    "wenn",
+    "habe",
+    "kann",
+    "gegen",
+    "vom",
+    "können",
+    "seine",
+    "ihre",
+    "sehr",
+    "zwei",
+    "dann",
+    "unter",
+    "wir",
+    "soll",
+    "ich",
+    "eines",
+    "Jahr",
+    "Jahre",
+    "dieser",
+    "wieder",
+    "keine",
+    "seiner",
+    "neue",
+    "was",
+    "alle",
+    "will",
+    "drei",
+    "diese",
+    "müssen",
+    "ihr",
+    "immer",
+    "dort",
+    "gibt",
+    "jetzt",
+    "zwischen",
+    "lassen",
+    "weil",
+    "selbst",
+    "andere",
+    "doch",
+    "geht",
+    "ohne",
+    "seit",
+    "damit",
+    "kein",
+    "ihm",
+    "ganz",
+    "etwas",
+    "viele",
+    "machen",
+    "große",
+    "neuen",
+    "ersten",
+    "Stadt",
+    "sagen",
+    "heute",
+    "leben",
+    "gut",
+    "gehen",
+    "Zeit",
+    "Mann",
+    "Frau",
+    "Kinder",
+    "Land",
+    "Welt",
+    "Arbeit",
+    "Haus",
+    "Tag",
+    "kommen",
+    "sehen",
+    "geben",
+    "wissen",
+    "denken",
+    "nehmen",
+    "finden",
+    "bleiben",
+    "liegen",
+    "stehen",
+    "stellen",
+    "spielen",
+    "halten",
+    "bringen",
+    "laufen",
+    "schreiben",
+    "lesen",
+    "sprechen",
+    "fragen",
+    "Antwort",
+    "Wasser",
+    "Schule",
+    "Freund",
+    "Familie",
+    "Weg",
+    "Nacht",
+    "Morgen",
+    "Abend",
+    "Hand",
+    "Kopf",
+    "Auge",
+    "Woche",
+    "Monat",
+    "Geld",
+    "Buch",
+    "Recht",
+    "Teil",
+    "Platz",
+    "Name",
+    "Wort",
+    "Frage",
+    "Beispiel",
+    "Ende",
+    "Anfang",
+    "Problem",
+    "Grund",
+    "Fall",
+    "Kraft",
+    "Sache",
+    "Mensch",
+    "Menschen",
+    "Stunde",
+    "Minute",
+    "klein",
+    "groß",
+    "alt",
+    "jung",
+    "lang",
+    "kurz",
+    "schnell",
+    "hoch",
+    "tief",
+    "früh",
+    "spät",
+    "leicht",
+    "schwer",
+    "richtig",
+    "falsch",
+    "wichtig",
+    "möglich",
+    "einfach",
+];
@@ -0,0 +1,81 @@
+use std::fs::{create_dir_all, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum TestMode {
+    Time,
+    Words,
+    Quote,
+}
+
+/// Single finished test, stored as one JSON line in history file
+#[derive(Serialize, Deserialize, Debug)]
+pub struct HistoryEntry {
+    pub timestamp: DateTime<Local>,
+    pub mode: TestMode,
+    /// Only time mode has a timeframe
+    #[serde(default)]
+    pub timeframe: Option<u64>,
+    pub words_count: Option<u64>,
+    pub words_list: String,
+    pub min_word_length: usize,
+    pub max_word_length: usize,
+    pub wpm: f64,
+    pub raw_wpm: f64,
+    pub accuracy: f64,
+    pub characters: usize,
+    pub correct_characters: usize,
+    #[serde(default)]
+    pub extra_characters: usize,
+    #[serde(default)]
+    pub missed_characters: usize,
+    #[serde(default)]
+    pub seed: Option<u64>,
+    #[serde(default)]
+    pub daily: bool,
+}
+
+/// History lives in `$XDG_DATA_HOME/typestorm/history.jsonl`
+pub fn get_history_path() -> Option<PathBuf> {
+    dirs::data_dir().map(|it| it.join("typestorm").join("history.jsonl"))
+}
+
+pub fn append_to_history(entry: &HistoryEntry) -> std::io::Result<()> {
+    let path = get_history_path().ok_or_else(|| {
+        std::io::Error::new(std::io::ErrorKind::NotFound, "Can't find data directory")
+    })?;
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", serde_json::to_string(entry)?)
+}
+
+/// Reads all entries from history file, lines that can't be parsed are skipped
+pub fn load_history() -> std::io::Result<Vec<HistoryEntry>> {
+    let path = match get_history_path() {
+        Some(path) if path.exists() => path,
+        _ => return Ok(vec![]),
+    };
+    let content = std::fs::read_to_string(path)?;
+    Ok(content
+        .lines()
+        .filter_map(|it| serde_json::from_str(it).ok())
+        .collect())
+}
+
+impl HistoryEntry {
+    /// Settings that make results comparable, e.g. `english, 60s`
+    pub fn configuration(&self) -> String {
+        match self.mode {
+            TestMode::Time => format!("{}, {}s", self.words_list, self.timeframe.unwrap_or(0)),
+            TestMode::Words => format!("{}, {} words", self.words_list, self.words_count.unwrap_or(0)),
+            TestMode::Quote => String::from("quote"),
+        }
+    }
+}
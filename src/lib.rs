@@ -0,0 +1,23 @@
+//! Typing test engine without any UI. `TypingSession` takes keystrokes with timestamps and
+//! exposes typed text, cursor and statistics, frontends only have to draw them.
+
+mod english1k_words;
+mod english_words;
+mod french_words;
+mod german_words;
+mod polish_words;
+mod portuguese_words;
+mod spanish_words;
+
+pub mod cursor_position;
+pub mod event_handler;
+pub mod playback;
+pub mod quotes;
+pub mod render;
+pub mod session;
+pub mod stats;
+pub mod theme;
+pub mod words;
+pub mod words_file;
+pub mod words_generator;
+pub mod words_lists;
@@ -1,54 +1,64 @@
 use std::ffi::c_int;
+use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant};
 
-use clap::Parser;
+use chrono::Local;
+use clap::{CommandFactory, FromArgMatches, Parser, ValueEnum};
 use libc::SIGINT;
 use ncurses::*;
-use terminal_size::terminal_size;
-
-use crate::cursor_position::CursorPosition;
-use crate::event_handler::{on_backspace, on_keypress};
-use crate::words::{shuffle_and_get_words, Words};
-use crate::words::Status::Unmark;
-
-mod cursor_position;
-mod english1k_words;
-mod english_words;
-mod event_handler;
-mod words;
-
-enum ColorsPair {
-    White = 1,
-    Green = 2,
-    Red = 3,
-    RedSpace = 4,
-}
+use typestorm::playback::{run_script, Script};
+use typestorm::quotes::{random_quote, Quote};
+use typestorm::render::{show_session, TypedLetters, LINES_TO_DISPLAY};
+use typestorm::session::{Key, TestLimit, TypingSession};
+use typestorm::stats::TestStats;
+use typestorm::theme::Theme;
+use typestorm::words::{filter_words, get_quote_words, shuffle_and_get_words, Words};
+use typestorm::words_file::load_words_file;
+use typestorm::words_generator::{take_most_frequent, SamplingMode};
+use typestorm::words_lists::{get_words_list, print_words_lists, WordsList};
 
-fn init_ncurses() {
-    initscr();
-    cbreak();
-    noecho();
-    keypad(stdscr(), true);
-    start_color();
-    use_default_colors();
-    init_pair(ColorsPair::White as i16, COLOR_WHITE, COLOR_BLACK);
-    init_pair(ColorsPair::Green as i16, COLOR_GREEN, COLOR_BLACK);
-    init_pair(ColorsPair::Red as i16, COLOR_RED, COLOR_BLACK);
-    init_pair(ColorsPair::RedSpace as i16, COLOR_RED, COLOR_RED);
-}
+use crate::config::load_config;
+use crate::daily::{get_today_challenge, print_today_best};
+use crate::history::{append_to_history, load_history, HistoryEntry, TestMode};
+use crate::output::{print_result, OutputFormat, TestResult, SCHEMA_VERSION};
+use crate::stats_report::{print_stats, StatsArgs};
+use crate::ui::{init_ncurses, NcursesRenderer};
+
+mod config;
+mod daily;
+mod history;
+mod output;
+mod stats_report;
+mod ui;
 
-#[derive(clap::ValueEnum, Clone)]
-pub enum WordsList {
-    English,
-    English1k,
+const BACKSPACE: char = '\u{7f}';
+const TAB: char = '\t';
+const STATUS_REFRESH_MS: i32 = 250;
+
+#[derive(clap::Subcommand)]
+enum Command {
+    /// Show personal bests, averages and daily trend from saved results
+    Stats(StatsArgs),
+    /// Play keystroke script without terminal UI and print final state and stats as JSON
+    Replay {
+        /// JSON file with `words`, `limit` and timestamped `keys`
+        script: PathBuf,
+    },
 }
 
 #[derive(Parser)]
-#[command(author, version, about, long_about = None)]
+#[command(author, version, about, long_about = None, args_conflicts_with_subcommands = true)]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
     #[arg(short, long, default_value_t = 60)]
     timeframe: u64,
 
+    /// End the test after typing this many words instead of after `timeframe` seconds
+    #[arg(long = "words", conflicts_with = "timeframe", value_parser = clap::value_parser!(u64).range(1..))]
+    words_count: Option<u64>,
+
     #[arg(long = "max", default_value_t = 1000)]
     max_word_length: usize,
 
@@ -58,52 +68,204 @@ struct Args {
     #[arg(short,long,value_enum, default_value_t = WordsList::English)]
     words_list: WordsList,
 
+    /// Newline-separated or JSON file with words to use instead of a built-in list
+    #[arg(long, conflicts_with = "words_list")]
+    words_file: Option<PathBuf>,
+
     #[arg(long, default_value_t = 4)]
     margin: usize,
+
+    /// How words are drawn from the list
+    #[arg(long, value_enum, default_value_t = SamplingMode::Uniform)]
+    sampling: SamplingMode,
+
+    /// Only use this many most frequent words from the list
+    #[arg(long, value_parser = clap::value_parser!(u64).range(1..))]
+    top: Option<u64>,
+
+    /// Seed for word selection, the same seed always gives the same words
+    #[arg(long)]
+    seed: Option<u64>,
+
+    /// Take today's challenge, words and test length are the same for everyone on a given day
+    #[arg(long, conflicts_with_all = [
+        "timeframe", "words_count", "words_list", "words_file", "quote",
+        "min_word_length", "max_word_length", "sampling", "top", "seed", "skip_on_space",
+    ])]
+    daily: bool,
+
+    /// Type a random quote in order, with punctuation and capitalization
+    #[arg(long, conflicts_with_all = [
+        "timeframe", "words_count", "words_list", "words_file",
+        "min_word_length", "max_word_length", "sampling", "top",
+    ])]
+    quote: bool,
+
+    /// Print available word lists and exit
+    #[arg(long)]
+    list_word_lists: bool,
+
+    /// Format of the result printed after the test
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    output: OutputFormat,
+
+    /// Space submits the word even if it isn't fully typed, skipped letters are counted as missed
+    #[arg(long)]
+    skip_on_space: bool,
+
+    /// Show letters typed instead of wrong ones over or under the text
+    #[arg(long, value_enum, default_value_t = TypedLetters::Hidden)]
+    typed_letters: TypedLetters,
+
+    /// Color theme: default, colorblind, monochrome or one defined in the config file
+    #[arg(long, default_value = "default")]
+    theme: String,
 }
 
 struct AppState {
     args: Args,
-    words: Words,
-    did_start_typing: bool,
-    now: Instant,
-    cursor: CursorPosition,
-    correctly_pressed_letters: usize,
-    all_letter_pressed: usize,
+    words_list: Vec<String>,
+    session: TypingSession,
+    quote: Option<&'static Quote>,
+    /// Moment from which session timestamps are measured
+    clock: Instant,
     terminal_width: usize,
+    theme: Theme,
 }
+fn generate_words(
+    args: &Args,
+    words_list: &[String],
+    terminal_width: usize,
+) -> (Words, Option<&'static Quote>) {
+    if args.quote {
+        let quote = random_quote(args.seed);
+        return (get_quote_words(quote, terminal_width, args.margin), Some(quote));
+    }
+    let words = shuffle_and_get_words(
+        words_list,
+        terminal_width,
+        args.margin,
+        args.words_count,
+        args.sampling,
+        args.seed,
+    );
+    (words, None)
+}
+
+fn create_session(
+    args: &Args,
+    words_list: &[String],
+    terminal_width: usize,
+) -> (TypingSession, Option<&'static Quote>) {
+    let (words, quote) = generate_words(args, words_list, terminal_width);
+    let limit = match (quote, args.words_count) {
+        (Some(_), _) => TestLimit::Words(words.data.len()),
+        (None, Some(words_count)) => TestLimit::Words(words_count as usize),
+        (None, None) => TestLimit::Time(Duration::from_secs(args.timeframe)),
+    };
+    let mut session = TypingSession::new(words, limit, terminal_width);
+    session.set_skip_on_space(args.skip_on_space);
+    (session, quote)
+}
+
 impl AppState {
-    fn new(args: Args, terminal_width: usize) -> Self {
+    fn new(args: Args, words_list: Vec<String>, terminal_width: usize, theme: Theme) -> Self {
+        let (session, quote) = create_session(&args, &words_list, terminal_width);
         Self {
-            words: shuffle_and_get_words(
-                &args.words_list,
-                args.min_word_length,
-                args.max_word_length,
-                terminal_width,
-                args.margin
-            ),
-            did_start_typing: false,
-            now: Instant::now(),
-            cursor: CursorPosition::new(args.margin),
-            correctly_pressed_letters: 0,
-            all_letter_pressed: 0,
+            session,
+            quote,
+            clock: Instant::now(),
             terminal_width,
+            words_list,
             args,
+            theme,
         }
     }
     fn reset(&mut self) {
-        self.words = shuffle_and_get_words(
-            &self.args.words_list,
-            self.args.min_word_length,
-            self.args.max_word_length,
-            self.terminal_width,
-            self.args.margin
-        );
-        self.did_start_typing = false;
-        self.now = Instant::now();
-        self.cursor = CursorPosition::new(self.args.margin);
-        self.correctly_pressed_letters = 0;
-        self.all_letter_pressed = 0;
+        (self.session, self.quote) = create_session(&self.args, &self.words_list, self.terminal_width);
+        self.clock = Instant::now();
+    }
+    fn mode(&self) -> TestMode {
+        if self.quote.is_some() {
+            TestMode::Quote
+        } else if self.args.words_count.is_some() {
+            TestMode::Words
+        } else {
+            TestMode::Time
+        }
+    }
+    /// Name of the words source, as shown in history
+    fn words_list_name(&self) -> String {
+        if self.quote.is_some() {
+            return String::from("quote");
+        }
+        match &self.args.words_file {
+            Some(path) => path.display().to_string(),
+            None => self.args.words_list.to_possible_value().unwrap().get_name().to_string(),
+        }
+    }
+    fn now(&self) -> Duration {
+        self.clock.elapsed()
+    }
+    /// How long to wait for a keypress, so neither status line refresh nor deadline is missed
+    fn get_input_timeout(&self) -> i32 {
+        match self.session.get_deadline() {
+            Some(deadline) => {
+                let remaining = deadline.saturating_sub(self.now()).as_millis();
+                remaining.min(STATUS_REFRESH_MS as u128) as i32
+            }
+            None => STATUS_REFRESH_MS,
+        }
+    }
+    fn get_live_stats(&self) -> TestStats {
+        self.session.get_stats(self.now())
+    }
+    fn get_status_line(&self) -> String {
+        let stats = self.get_live_stats();
+        let remaining = match self.session.get_limit() {
+            TestLimit::Words(words_count) => {
+                let completed = self.session.get_completed_words();
+                format!("{} words left", words_count.saturating_sub(completed))
+            }
+            TestLimit::Time(timeframe) => {
+                format!("{}s left", timeframe.saturating_sub(stats.duration).as_secs())
+            }
+        };
+        format!(
+            "{}   {} wpm   {}% acc",
+            remaining,
+            stats.net_wpm() as i64,
+            stats.accuracy() as i64
+        )
+    }
+    fn resize(&mut self) {
+        self.terminal_width = COLS() as usize;
+        self.session.resize(self.terminal_width);
+    }
+    fn draw(&mut self) {
+        let status_line = self.get_status_line();
+        let mut renderer = NcursesRenderer { theme: &self.theme };
+        show_session(&mut renderer, &mut self.session, &status_line, self.args.typed_letters);
+    }
+    fn is_finished(&self) -> bool {
+        self.session.is_finished(self.now())
+    }
+}
+
+fn replay(path: &Path) {
+    let result = std::fs::read_to_string(path)
+        .map_err(|err| format!("Can't read script {}: {}", path.display(), err))
+        .and_then(|it| {
+            serde_json::from_str::<Script>(&it)
+                .map_err(|err| format!("Invalid script {}: {}", path.display(), err))
+        })
+        .and_then(|script| run_script(&script, LINES_TO_DISPLAY).map_err(|err| err.to_string()));
+    match result {
+        Ok(result) => println!("{}", serde_json::to_string_pretty(&result).unwrap()),
+        Err(err) => {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        }
     }
 }
 
@@ -114,83 +276,177 @@ extern "C" fn handle_signal(_: c_int) {
 }
 
 fn main() {
-    let args = Args::parse();
-    let terminal_width = terminal_size().unwrap().0.0;
-    let timeframe_in_secs = args.timeframe;
-    let mut state = AppState::new(args, terminal_width as usize);
-    init_ncurses();
+    let matches = Args::command().get_matches();
+    let mut args = Args::from_arg_matches(&matches).unwrap_or_else(|err| err.exit());
+    match &args.command {
+        Some(Command::Stats(stats_args)) => {
+            print_stats(stats_args);
+            return;
+        }
+        Some(Command::Replay { script }) => {
+            replay(script);
+            return;
+        }
+        None => {}
+    }
+    if args.list_word_lists {
+        print_words_lists();
+        return;
+    }
+    let theme = match load_config().and_then(|config| {
+        config.apply(&mut args, &matches);
+        config.get_theme(&args.theme)
+    }) {
+        Ok(theme) => theme,
+        Err(err) => {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        }
+    };
+    let daily_challenge = args.daily.then(get_today_challenge);
+    if let Some(challenge) = &daily_challenge {
+        args.seed = Some(challenge.seed);
+        args.words_count = Some(challenge.words_count);
+        args.words_list = challenge.words_list.clone();
+    }
+    let words_list = match &args.words_file {
+        Some(path) => match load_words_file(path) {
+            Ok(words) => words,
+            Err(err) => {
+                eprintln!("{}", err);
+                std::process::exit(1);
+            }
+        },
+        None => get_words_list(&args.words_list),
+    };
+    let uses_frequency = args.top.is_some() || args.sampling == SamplingMode::Frequency;
+    // Order of words in a file is unknown, so it's never treated as frequency order
+    let ordered_by_frequency =
+        args.words_file.is_none() && args.words_list.info().ordered_by_frequency;
+    if uses_frequency && !ordered_by_frequency && !args.quote {
+        eprintln!(
+            "--top and --sampling frequency need a word list ordered by frequency, {} isn't",
+            match &args.words_file {
+                Some(path) => path.display().to_string(),
+                None => args.words_list.to_possible_value().unwrap().get_name().to_string(),
+            }
+        );
+        std::process::exit(1);
+    }
+    let words_list = take_most_frequent(&words_list, args.top);
+    let words_list = filter_words(words_list, args.min_word_length, args.max_word_length);
+    if words_list.is_empty() && !args.quote {
+        eprintln!(
+            "No words with length between {} and {}",
+            args.min_word_length, args.max_word_length
+        );
+        std::process::exit(1);
+    }
+    if let Err(err) = init_ncurses(&theme) {
+        eprintln!("Can't open terminal /dev/tty: {}", err);
+        std::process::exit(1);
+    }
+    let mut state = AppState::new(args, words_list, COLS() as usize, theme);
     // Install signal handler for interrupt signal
     unsafe {
-        libc::signal(SIGINT, handle_signal as usize);
+        libc::signal(SIGINT, handle_signal as *const () as usize);
     }
 
-    state.words.show_words(&mut state.cursor, state.terminal_width);
-    while state.now.elapsed() < Duration::from_secs(timeframe_in_secs) || !state.did_start_typing {
-        let c = getch();
-        if c != ERR {
-            clear();
-            let c = c as u8 as char;
-
-            // Start measuring time on first keypress
-            if !state.did_start_typing {
-                state.now = Instant::now();
-                state.did_start_typing = true;
+    state.draw();
+    while !state.is_finished() {
+        // Don't block on input, so status line is refreshed and test ends exactly on time
+        timeout(state.get_input_timeout());
+        let c = match get_wch() {
+            Some(WchResult::Char(c)) => char::from_u32(c),
+            // Some terminals send backspace as a key code instead of DEL character
+            Some(WchResult::KeyCode(KEY_BACKSPACE)) => Some(BACKSPACE),
+            Some(WchResult::KeyCode(KEY_RESIZE)) => {
+                state.resize();
+                None
             }
-
-            let mut did_mark_letter = false;
-            for i in 0..state.words.data.len() {
-                let word = &mut state.words.data[i];
-                if word.completed {
-                    continue;
-                }
-                // 127 is backspace
-                if c as u8 == 127 {
-                    // If on_backspace return false we have to modify word before him
-                    if !on_backspace(word, &mut state.cursor) && i != 0 {
-                        state.words.data[i - 1].letters.last_mut().unwrap().status = Unmark;
-                        state.words.data[i - 1].completed = false;
-                        if state.cursor.get_x() == 0 {
-                            state.cursor.go_back_to_old_line();
-                        } else {
-                            state.cursor.move_left();
-                        }
-                    }
-                    break;
-                }
-                // 9 == Tab Reset
-                else if c as u8 == 9 {
-                    state.reset();
-                    break;
-                } else if on_keypress(
-                    word,
-                    c,
-                    &mut did_mark_letter,
-                    &mut state.cursor,
-                    &mut state.correctly_pressed_letters,
-                    &mut state.all_letter_pressed,
-                ) {
-                    break;
-                }
+            _ => None,
+        };
+        // Keystrokes that came after the deadline don't count
+        if state.is_finished() {
+            break;
+        }
+        if let Some(c) = c {
+            // Ignore keys like enter or escape, there is nothing to type them against
+            if c.is_control() && c != BACKSPACE && c != TAB {
+                continue;
+            }
+            // Tab resets the test
+            if c == TAB {
+                state.reset();
+            } else {
+                let key = if c == BACKSPACE { Key::Backspace } else { Key::Char(c) };
+                let now = state.now();
+                state.session.on_key(key, now);
             }
-            state.words.show_words(&mut state.cursor, state.terminal_width);
         }
+        // Redraw even without keypress to keep status line up to date
+        state.draw();
     }
+    let stats = state.get_live_stats();
+    // Drop keys typed after the test ended instead of passing them to the shell
+    flushinp();
     endwin();
 
-    let average_word_length = state.words
-        .data
-        .iter()
-        .filter(|it| it.completed)
-        .map(|it| it.letters.len())
-        .sum::<usize>() as f64
-        / state.words.data.iter().filter(|it| it.completed).count() as f64;
-    println!(
-        "Accuracy {}%",
-        ((state.correctly_pressed_letters as f64 / state.all_letter_pressed as f64) * 100.0) as i64
-    );
-    println!(
-        "WPM {}",
-        ((state.all_letter_pressed as f64 / average_word_length) / (timeframe_in_secs as f64 / 60.0))
-            as i64
-    );
+    let mode = state.mode();
+    let result = TestResult {
+        schema_version: SCHEMA_VERSION,
+        timestamp: Local::now(),
+        mode,
+        timeframe: (mode == TestMode::Time).then_some(state.args.timeframe),
+        words_count: state.args.words_count,
+        words_list: state.words_list_name(),
+        min_word_length: state.args.min_word_length,
+        max_word_length: state.args.max_word_length,
+        sampling: state.args.sampling,
+        top: state.args.top,
+        seed: state.args.seed,
+        daily: state.args.daily,
+        duration_secs: stats.duration.as_secs_f64(),
+        net_wpm: stats.net_wpm(),
+        raw_wpm: stats.raw_wpm(),
+        accuracy: stats.accuracy(),
+        correct_characters: stats.correct_characters,
+        incorrect_characters: stats.all_characters - stats.correct_characters,
+        extra_characters: stats.extra_characters,
+        missed_characters: stats.missed_characters,
+    };
+    print_result(state.args.output, &result, state.quote, &state.session.get_mistakes());
+
+    // Nothing to remember if not a single letter was typed
+    if stats.all_characters == 0 {
+        return;
+    }
+    let entry = HistoryEntry {
+        timestamp: result.timestamp,
+        mode,
+        timeframe: result.timeframe,
+        words_count: result.words_count,
+        words_list: result.words_list.clone(),
+        min_word_length: result.min_word_length,
+        max_word_length: result.max_word_length,
+        wpm: result.net_wpm,
+        raw_wpm: result.raw_wpm,
+        accuracy: result.accuracy,
+        characters: stats.all_characters,
+        correct_characters: stats.correct_characters,
+        extra_characters: stats.extra_characters,
+        missed_characters: stats.missed_characters,
+        seed: result.seed,
+        daily: result.daily,
+    };
+    if let Err(err) = append_to_history(&entry) {
+        eprintln!("Can't save result to history: {}", err);
+    }
+    // Summary is meant for people, scripts get only the result
+    if let (Some(challenge), OutputFormat::Text) = (&daily_challenge, state.args.output) {
+        match load_history() {
+            Ok(history) => print_today_best(challenge, &history),
+            Err(err) => eprintln!("Can't read history: {}", err),
+        }
+    }
 }
@@ -0,0 +1,180 @@
+use chrono::{DateTime, Local};
+use serde::Serialize;
+use typestorm::quotes::Quote;
+use typestorm::stats::Mistake;
+use typestorm::words_generator::SamplingMode;
+
+use crate::history::TestMode;
+
+/// Bump when fields are renamed or removed, new fields can be added without it
+pub const SCHEMA_VERSION: u32 = 1;
+/// Only the most common mistakes are listed after the test
+const MISTAKES_TO_SHOW: usize = 10;
+
+#[derive(clap::ValueEnum, serde::Deserialize, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    Text,
+    Json,
+    Csv,
+}
+
+/// Final result of a test, in the shape printed by `--output json` and `--output csv`
+#[derive(Serialize, Debug)]
+pub struct TestResult {
+    pub schema_version: u32,
+    pub timestamp: DateTime<Local>,
+    pub mode: TestMode,
+    pub timeframe: Option<u64>,
+    pub words_count: Option<u64>,
+    pub words_list: String,
+    pub min_word_length: usize,
+    pub max_word_length: usize,
+    pub sampling: SamplingMode,
+    pub top: Option<u64>,
+    pub seed: Option<u64>,
+    pub daily: bool,
+    pub duration_secs: f64,
+    pub net_wpm: f64,
+    pub raw_wpm: f64,
+    pub accuracy: f64,
+    pub correct_characters: usize,
+    /// Includes extra characters
+    pub incorrect_characters: usize,
+    /// Characters typed past the end of words
+    pub extra_characters: usize,
+    /// Letters skipped with `--skip-on-space`, not included in other counts
+    pub missed_characters: usize,
+}
+
+/// Mistakes are listed only in text output
+pub fn print_result(
+    format: OutputFormat,
+    result: &TestResult,
+    quote: Option<&Quote>,
+    mistakes: &[Mistake],
+) {
+    match format {
+        OutputFormat::Text => print_text(result, quote, mistakes),
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(result).unwrap()),
+        OutputFormat::Csv => print!("{}", to_csv(result)),
+    }
+}
+
+fn print_text(result: &TestResult, quote: Option<&Quote>, mistakes: &[Mistake]) {
+    println!("Accuracy {}%", result.accuracy as i64);
+    println!("WPM {}", result.net_wpm as i64);
+    println!("Raw WPM {}", result.raw_wpm as i64);
+    if result.extra_characters > 0 {
+        println!("Extra characters {}", result.extra_characters);
+    }
+    if result.missed_characters > 0 {
+        println!("Missed characters {}", result.missed_characters);
+    }
+    if let Some(seed) = result.seed {
+        println!("Seed {}", seed);
+    }
+    if !mistakes.is_empty() {
+        println!();
+        println!("Mistakes (expected -> typed)");
+        for mistake in mistakes.iter().take(MISTAKES_TO_SHOW) {
+            let expected = show_letter(mistake.expected);
+            println!("  {} -> {}  ({})", expected, show_letter(mistake.typed), mistake.count);
+        }
+    }
+    if let Some(quote) = quote {
+        println!();
+        println!("\"{}\"", quote.text);
+        println!("  - {}, {}", quote.author, quote.source);
+    }
+}
+
+fn show_letter(letter: char) -> String {
+    match letter {
+        ' ' => String::from("space"),
+        _ => letter.to_string(),
+    }
+}
+
+/// Header and a single row, columns in the same order as JSON fields
+fn to_csv(result: &TestResult) -> String {
+    let value = serde_json::to_value(result).unwrap();
+    let fields = value.as_object().unwrap();
+    let header = fields.keys().cloned().collect::<Vec<String>>();
+    let row = fields
+        .values()
+        .map(|it| match it {
+            serde_json::Value::Null => String::new(),
+            serde_json::Value::String(text) => csv_field(text),
+            other => other.to_string(),
+        })
+        .collect::<Vec<String>>();
+    format!("{}\n{}\n", header.join(","), row.join(","))
+}
+
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n']) {
+        return format!("\"{}\"", text.replace('"', "\"\""));
+    }
+    text.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn result(words_list: &str) -> TestResult {
+        TestResult {
+            schema_version: SCHEMA_VERSION,
+            timestamp: Local.timestamp_opt(1_700_000_000, 0).unwrap(),
+            mode: TestMode::Words,
+            timeframe: None,
+            words_count: Some(25),
+            words_list: words_list.to_string(),
+            min_word_length: 0,
+            max_word_length: 1000,
+            sampling: SamplingMode::Uniform,
+            top: None,
+            seed: Some(7),
+            daily: false,
+            duration_secs: 12.5,
+            net_wpm: 48.0,
+            raw_wpm: 52.0,
+            accuracy: 92.5,
+            correct_characters: 100,
+            incorrect_characters: 8,
+            extra_characters: 2,
+            missed_characters: 0,
+        }
+    }
+
+    /// Dashboards depend on these names and their order, add new fields only at the end
+    const FIELDS: [&str; 20] = [
+        "schema_version", "timestamp", "mode", "timeframe", "words_count", "words_list",
+        "min_word_length", "max_word_length", "sampling", "top", "seed", "daily", "duration_secs",
+        "net_wpm", "raw_wpm", "accuracy", "correct_characters", "incorrect_characters",
+        "extra_characters", "missed_characters",
+    ];
+
+    #[test]
+    fn json_and_csv_fields_keep_their_order() {
+        let json = serde_json::to_value(result("english")).unwrap();
+        let keys = json.as_object().unwrap().keys().collect::<Vec<&String>>();
+        assert_eq!(keys, FIELDS);
+
+        let csv = to_csv(&result("english"));
+        let lines = csv.lines().collect::<Vec<&str>>();
+        assert_eq!(lines[0], FIELDS.join(","));
+        assert!(lines[1].starts_with("1,"));
+        let row = ",words,,25,english,0,1000,uniform,,7,false,12.5,48.0,52.0,92.5,100,8,2,0";
+        assert!(lines[1].ends_with(row));
+    }
+
+    #[test]
+    fn csv_quotes_fields_with_commas_and_quotes() {
+        let csv = to_csv(&result(r#"my,"words".txt"#));
+        assert!(csv.contains(r#",25,"my,""words"".txt",0,"#));
+    }
+}
@@ -0,0 +1,320 @@
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::session::{Key, TestLimit, TypingSession};
+use crate::words::{get_fixed_words, Status};
+
+/// Keystrokes with timestamps typed against a fixed sequence of words, e.g.
+/// `{"words": ["abc", "def"], "limit": {"words": 2}, "keys": [{"at_ms": 0, "key": "a"}]}`
+#[derive(Deserialize, Debug)]
+pub struct Script {
+    pub words: Vec<String>,
+    #[serde(default = "default_terminal_width")]
+    pub terminal_width: usize,
+    #[serde(default = "default_margin")]
+    pub margin: usize,
+    pub limit: ScriptLimit,
+    pub keys: Vec<ScriptKey>,
+    /// Moment at which state and stats are taken, defaults to the last keystroke
+    pub end_ms: Option<u64>,
+    /// Space submits the word even if it isn't fully typed
+    #[serde(default)]
+    pub skip_on_space: bool,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum ScriptLimit {
+    TimeMs(u64),
+    Words(usize),
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ScriptKey {
+    pub at_ms: u64,
+    /// Single character or `backspace`
+    pub key: String,
+}
+
+#[derive(Debug)]
+pub enum PlaybackError {
+    InvalidKey(usize, String),
+    /// Key pressed earlier than the one before it
+    UnorderedKey(usize),
+}
+
+impl Display for PlaybackError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PlaybackError::InvalidKey(index, key) => write!(
+                f,
+                "Key number {} ({:?}) must be a single character or `backspace`",
+                index + 1,
+                key
+            ),
+            PlaybackError::UnorderedKey(index) => write!(
+                f,
+                "Key number {} is pressed before the previous one, `at_ms` must not decrease",
+                index + 1
+            ),
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct PlaybackResult {
+    pub finished: bool,
+    pub completed_words: usize,
+    pub cursor_x: usize,
+    pub cursor_line: usize,
+    /// Words in visible lines, first one is the current line
+    pub lines: Vec<Vec<String>>,
+    /// Every word with one status character per letter: `.` unmarked, `c` correct, `w` wrong,
+    /// `e` extra, `m` missed
+    pub words: Vec<PlaybackWord>,
+    pub duration_ms: u128,
+    pub net_wpm: f64,
+    pub raw_wpm: f64,
+    pub accuracy: f64,
+    pub correct_characters: usize,
+    pub all_characters: usize,
+    pub extra_characters: usize,
+    pub missed_characters: usize,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+pub struct PlaybackWord {
+    pub text: String,
+    pub status: String,
+    pub completed: bool,
+}
+
+fn default_terminal_width() -> usize {
+    80
+}
+
+fn default_margin() -> usize {
+    4
+}
+
+pub fn parse_key(key: &str) -> Option<Key> {
+    if key == "backspace" {
+        return Some(Key::Backspace);
+    }
+    let mut chars = key.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(Key::Char(c)),
+        _ => None,
+    }
+}
+
+/// Plays script on a new session and returns its final state, `lines_count` lines are reported
+pub fn run_script(script: &Script, lines_count: usize) -> Result<PlaybackResult, PlaybackError> {
+    let limit = match script.limit {
+        ScriptLimit::TimeMs(ms) => TestLimit::Time(Duration::from_millis(ms)),
+        ScriptLimit::Words(words) => TestLimit::Words(words),
+    };
+    // Test that ends with the last word ends on its last letter, just like in words mode
+    let ends_with_last_word =
+        matches!(script.limit, ScriptLimit::Words(words) if words == script.words.len());
+    let last_space = !ends_with_last_word;
+    let words = get_fixed_words(&script.words, script.terminal_width, script.margin, last_space);
+    let mut session = TypingSession::new(words, limit, script.terminal_width);
+    session.set_skip_on_space(script.skip_on_space);
+
+    for (i, it) in script.keys.iter().enumerate() {
+        let key = parse_key(&it.key).ok_or_else(|| PlaybackError::InvalidKey(i, it.key.clone()))?;
+        if i > 0 && it.at_ms < script.keys[i - 1].at_ms {
+            return Err(PlaybackError::UnorderedKey(i));
+        }
+        session.on_key(key, Duration::from_millis(it.at_ms));
+    }
+
+    let end = script
+        .end_ms
+        .or(script.keys.last().map(|it| it.at_ms))
+        .map(Duration::from_millis)
+        .unwrap_or_default();
+    let stats = session.get_stats(end);
+    let lines = session
+        .get_lines_to_display(lines_count)
+        .into_iter()
+        .map(|line| {
+            session.get_words().data[line]
+                .iter()
+                .map(|word| word.letters.iter().map(|it| it.current_letter).collect::<String>())
+                .map(|word| word.trim_end().to_string())
+                .collect()
+        })
+        .collect();
+    let words = session
+        .get_words()
+        .data
+        .iter()
+        .map(|word| PlaybackWord {
+            text: word.letters.iter().map(|it| it.current_letter).collect(),
+            status: word
+                .letters
+                .iter()
+                .map(|it| match it.status {
+                    Status::Unmark => '.',
+                    Status::Correct => 'c',
+                    Status::Wrong => 'w',
+                    Status::Extra => 'e',
+                    Status::Missed => 'm',
+                })
+                .collect(),
+            completed: word.completed,
+        })
+        .collect();
+
+    Ok(PlaybackResult {
+        finished: session.is_finished(end),
+        completed_words: session.get_completed_words(),
+        cursor_x: session.get_cursor().get_x(),
+        cursor_line: session.get_cursor().get_line_position(),
+        lines,
+        words,
+        duration_ms: stats.duration.as_millis(),
+        net_wpm: stats.net_wpm(),
+        raw_wpm: stats.raw_wpm(),
+        accuracy: stats.accuracy(),
+        correct_characters: stats.correct_characters,
+        all_characters: stats.all_characters,
+        extra_characters: stats.extra_characters,
+        missed_characters: stats.missed_characters,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Script typing `typed` one key every 100ms, `<` stands for backspace
+    fn script(words: &[&str], terminal_width: usize, typed: &str) -> Script {
+        let keys = typed
+            .chars()
+            .enumerate()
+            .map(|(i, c)| ScriptKey {
+                at_ms: i as u64 * 100,
+                key: if c == '<' { String::from("backspace") } else { c.to_string() },
+            })
+            .collect();
+        Script {
+            words: words.iter().map(|it| it.to_string()).collect(),
+            terminal_width,
+            margin: 4,
+            limit: ScriptLimit::Words(words.len()),
+            keys,
+            end_ms: None,
+            skip_on_space: false,
+        }
+    }
+
+    fn play(words: &[&str], terminal_width: usize, typed: &str) -> PlaybackResult {
+        run_script(&script(words, terminal_width, typed), 3).unwrap()
+    }
+
+    #[test]
+    fn parses_script_json() {
+        let script: Script = serde_json::from_str(
+            r#"{"words": ["ab"], "limit": {"time_ms": 1000}, "keys": [{"at_ms": 5, "key": "a"}]}"#,
+        )
+        .unwrap();
+        let result = run_script(&script, 1).unwrap();
+        assert_eq!(result.words[0].status, "c..");
+        assert_eq!(result.cursor_x, 5);
+    }
+
+    #[test]
+    fn rejects_unknown_keys() {
+        let script: Script = serde_json::from_str(
+            r#"{"words": ["ab"], "limit": {"words": 1}, "keys": [{"at_ms": 0, "key": "enter"}]}"#,
+        )
+        .unwrap();
+        assert!(run_script(&script, 1).is_err());
+    }
+
+    #[test]
+    fn cursor_moves_by_display_width() {
+        let result = play(&["日本", "ab"], 80, "日x");
+        assert_eq!(result.words[0].status, "cw.");
+        assert_eq!(result.cursor_x, 4 + 4);
+    }
+
+    #[test]
+    fn wraps_line_when_it_is_completed() {
+        let result = play(&["abc", "def", "ghi", "jkl"], 20, "abc def ");
+        assert_eq!(result.cursor_line, 1);
+        assert_eq!(result.cursor_x, 4);
+        assert_eq!(result.lines[0], vec!["ghi", "jkl"]);
+    }
+
+    #[test]
+    fn backspace_across_line_boundary() {
+        let result = play(&["abc", "def", "ghi", "jkl"], 20, "abc def <");
+        assert_eq!(result.cursor_line, 0);
+        assert_eq!(result.cursor_x, 4 + 7);
+        assert_eq!(result.lines[0], vec!["abc", "def"]);
+        assert_eq!(result.words[1].status, "ccc.");
+        assert!(!result.words[1].completed);
+    }
+
+    #[test]
+    fn backspace_across_word_boundary() {
+        let result = play(&["abc", "def"], 80, "abc d<<");
+        assert_eq!(result.words[0].status, "ccc.");
+        assert_eq!(result.words[1].status, "...");
+        assert_eq!(result.completed_words, 0);
+        assert_eq!(result.cursor_x, 4 + 3);
+
+        let result = play(&["abc", "def"], 80, "abc d<<x");
+        assert_eq!(result.words[0].text, "abcx ");
+        assert_eq!(result.words[0].status, "ccce.");
+        assert_eq!(result.completed_words, 0);
+        assert_eq!(result.cursor_x, 4 + 4);
+        assert_eq!(result.extra_characters, 1);
+    }
+
+    #[test]
+    fn reports_stats_of_finished_test() {
+        let result = play(&["ab", "cd"], 80, "ab c");
+        assert!(!result.finished);
+        let result = play(&["ab", "cd"], 80, "ab cx");
+        assert!(result.finished);
+        assert_eq!(result.completed_words, 2);
+        assert_eq!(result.words[1].text, "cd");
+        assert_eq!(result.correct_characters, 4);
+        assert_eq!(result.all_characters, 5);
+        assert_eq!(result.duration_ms, 400);
+    }
+
+    #[test]
+    fn space_skips_rest_of_word() {
+        let mut script = script(&["abc", "def"], 14, "a  d");
+        script.skip_on_space = true;
+        let result = run_script(&script, 3).unwrap();
+        assert_eq!(result.words[0].status, "cmmc");
+        assert_eq!(result.words[1].status, "c..");
+        assert_eq!((result.cursor_line, result.cursor_x), (1, 4 + 1));
+        assert_eq!(result.missed_characters, 2);
+        assert_eq!(result.all_characters, 3);
+
+        // Going back to skipped word puts cursor after its last typed letter
+        script.keys.truncate(2);
+        script.keys.push(ScriptKey { at_ms: 300, key: String::from("backspace") });
+        let result = run_script(&script, 3).unwrap();
+        assert_eq!(result.words[0].status, "c...");
+        assert_eq!((result.cursor_line, result.cursor_x), (0, 4 + 1));
+        assert_eq!(result.missed_characters, 0);
+    }
+
+    #[test]
+    fn rejects_keys_out_of_order() {
+        let mut script = script(&["a", "b"], 80, "a ");
+        script.keys[0].at_ms = 200;
+        assert!(matches!(run_script(&script, 3), Err(PlaybackError::UnorderedKey(1))));
+    }
+}
@@ -0,0 +1,182 @@
+pub static WORDS: [&str; 180] = [
+    "i",
+    "w",
+    "nie",
+    "na",
+    "z",
+    "się",
+    "do",
+    "to",
+    "że",
+    "a",
+    "o",
+    "jak",
+    "ale",
+    "po",
+    "co",
+    "jest",
+    "tak",
+    "za",
+    "od",
+    "go",
+    "już",
+    "jego",
+    "jej",
+    "czy",
+    "tylko",
+    "może",
+    "przez",
+    "ich",
+    "ja",
+    "jeszcze",
+    "mnie",
+    "by",
+    "są",
+    "też",
+    "był",
+    "pan",
+    "tym",
+    "mi",
+    "nawet",
+    "więc",
+    "kiedy",
+    "bardzo",
+    "być",
+    "ten",
+    "ta",
+    "te",
+    "tego",
+    "tej",
+    "które",
+    "który",
+    "która",
+    "gdy",
+    "tu",
+    "teraz",
+    "tam",
+    "przy",
+    "sobie",
+    "siebie",
+    "bez",
+    "pod",
+    "nad",
+    "przed",
+    "między",
+    "dla",
+    "jednak",
+    "wszystko",
+    "nic",
+    "coś",
+    "ktoś",
+    "nikt",
+    "zawsze",
+    "nigdy",
+    "dzisiaj",
+    "jutro",
+    "wczoraj",
+    "dobrze",
+    "źle",
+    "mówić",
+    "robić",
+    "mieć",
+    "wiedzieć",
+    "chcieć",
+    "móc",
+    "musieć",
+    "iść",
+    "widzieć",
+    "dać",
+    "wziąć",
+    "powiedzieć",
+    "zrobić",
+    "pójść",
+    "myśleć",
+    "czekać",
+    "szukać",
+    "znaleźć",
+    "pisać",
+    "czytać",
+    "pracować",
+    "mieszkać",
+    "żyć",
+    "kochać",
+    "lubić",
+    "jeść",
+    "pić",
+    "spać",
+    "stać",
+    "siedzieć",
+    "leżeć",
+    "patrzeć",
+    "słuchać",
+    "pytać",
+    "odpowiadać",
+    "rozumieć",
+    "pamiętać",
+    "zacząć",
+    "skończyć",
+    "wrócić",
+    "wyjść",
+    "wejść",
+    "człowiek",
+    "ludzie",
+    "dzień",
+    "rok",
+    "czas",
+    "życie",
+    "dom",
+    "ręka",
+    "oko",
+    "głowa",
+    "świat",
+    "miasto",
+    "kraj",
+    "sprawa",
+    "praca",
+    "woda",
+    "ziemia",
+    "noc",
+    "słowo",
+    "rzecz",
+    "droga",
+    "strona",
+    "dziecko",
+    "kobieta",
+    "mężczyzna",
+    "matka",
+    "ojciec",
+    "syn",
+    "córka",
+    "brat",
+    "siostra",
+    "przyjaciel",
+    "szkoła",
+    "książka",
+    "pieniądze",
+    "samochód",
+    "drzwi",
+    "okno",
+    "stół",
+    "pokój",
+    "miejsce",
+    "chwila",
+    "godzina",
+    "tydzień",
+    "miesiąc",
+    "duży",
+    "mały",
+    "dobry",
+    "zły",
+    "nowy",
+    "stary",
+    "młody",
+    "długi",
+    "krótki",
+    "wysoki",
+    "niski",
+    "pierwszy",
+    "ostatni",
+    "cały",
+    "każdy",
+    "inny",
+];
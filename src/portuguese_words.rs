@@ -0,0 +1,191 @@
+pub static WORDS: [&str; 189] = [
+    "de",
+    "a",
+    "o",
+    "que",
+    "e",
+    "do",
+    "da",
+    "em",
+    "um",
+    "para",
+    "é",
+    "com",
+    "não",
+    "uma",
+    "os",
+    "no",
+    "se",
+    "na",
+    "por",
+    "mais",
+    "as",
+    "dos",
+    "como",
+    "mas",
+    "foi",
+    "ao",
+    "ele",
+    "das",
+    "tem",
+    "à",
+    "seu",
+    "sua",
+    "ou",
+    "ser",
+    "quando",
+    "muito",
+    "há",
+    "nos",
+    "já",
+    "está",
+    "eu",
+    "também",
+    "só",
+    "pelo",
+    "pela",
+    "até",
+    "isso",
+    "ela",
+    "entre",
+    "era",
+    "depois",
+    "sem",
+    "mesmo",
+    "aos",
+    "ter",
+    "seus",
+    "quem",
+    "nas",
+    "me",
+    "esse",
+    "eles",
+    "estão",
+    "você",
+    "tinha",
+    "foram",
+    "essa",
+    "num",
+    "nem",
+    "suas",
+    "meu",
+    "às",
+    "minha",
+    "têm",
+    "numa",
+    "pelos",
+    "elas",
+    "havia",
+    "seja",
+    "qual",
+    "será",
+    "nós",
+    "tenho",
+    "lhe",
+    "deles",
+    "essas",
+    "esses",
+    "pelas",
+    "este",
+    "fosse",
+    "dele",
+    "tu",
+    "te",
+    "vocês",
+    "vos",
+    "lhes",
+    "meus",
+    "minhas",
+    "teu",
+    "tua",
+    "teus",
+    "tuas",
+    "nosso",
+    "nossa",
+    "nossos",
+    "nossas",
+    "dela",
+    "delas",
+    "esta",
+    "estes",
+    "estas",
+    "aquele",
+    "aquela",
+    "aqueles",
+    "aquelas",
+    "isto",
+    "aquilo",
+    "fazer",
+    "dizer",
+    "poder",
+    "ir",
+    "ver",
+    "dar",
+    "saber",
+    "querer",
+    "chegar",
+    "passar",
+    "ficar",
+    "deixar",
+    "parecer",
+    "levar",
+    "começar",
+    "conhecer",
+    "viver",
+    "sentir",
+    "pensar",
+    "falar",
+    "trabalhar",
+    "encontrar",
+    "voltar",
+    "entrar",
+    "sair",
+    "chamar",
+    "olhar",
+    "precisar",
+    "tempo",
+    "ano",
+    "dia",
+    "vez",
+    "casa",
+    "mundo",
+    "vida",
+    "homem",
+    "parte",
+    "país",
+    "forma",
+    "caso",
+    "momento",
+    "lugar",
+    "pessoa",
+    "trabalho",
+    "mulher",
+    "governo",
+    "ponto",
+    "água",
+    "noite",
+    "cidade",
+    "pai",
+    "mãe",
+    "filho",
+    "hora",
+    "nome",
+    "coisa",
+    "amigo",
+    "escola",
+    "livro",
+    "porta",
+    "olho",
+    "mão",
+    "cabeça",
+    "palavra",
+    "história",
+    "grande",
+    "pequeno",
+    "bom",
+    "novo",
+    "primeiro",
+    "último",
+    "velho",
+    "jovem",
+];
@@ -0,0 +1,96 @@
+use rand::seq::SliceRandom;
+
+use crate::words_generator::create_rng;
+
+pub struct Quote {
+    pub text: &'static str,
+    pub author: &'static str,
+    pub source: &'static str,
+}
+
+pub fn random_quote(seed: Option<u64>) -> &'static Quote {
+    QUOTES.choose(&mut create_rng(seed)).unwrap()
+}
+
+pub static QUOTES: [Quote; 16] = [
+    Quote {
+        text: "It is a truth universally acknowledged, that a single man in possession of a good fortune, must be in want of a wife.",
+        author: "Jane Austen",
+        source: "Pride and Prejudice",
+    },
+    Quote {
+        text: "It was the best of times, it was the worst of times, it was the age of wisdom, it was the age of foolishness.",
+        author: "Charles Dickens",
+        source: "A Tale of Two Cities",
+    },
+    Quote {
+        text: "Call me Ishmael. Some years ago - never mind how long precisely - having little or no money in my purse, I thought I would sail about a little and see the watery part of the world.",
+        author: "Herman Melville",
+        source: "Moby-Dick",
+    },
+    Quote {
+        text: "All happy families are alike; each unhappy family is unhappy in its own way.",
+        author: "Leo Tolstoy",
+        source: "Anna Karenina",
+    },
+    Quote {
+        text: "It is not down in any map; true places never are.",
+        author: "Herman Melville",
+        source: "Moby-Dick",
+    },
+    Quote {
+        text: "Alice was beginning to get very tired of sitting by her sister on the bank, and of having nothing to do.",
+        author: "Lewis Carroll",
+        source: "Alice's Adventures in Wonderland",
+    },
+    Quote {
+        text: "To be, or not to be, that is the question: Whether 'tis nobler in the mind to suffer the slings and arrows of outrageous fortune, or to take arms against a sea of troubles.",
+        author: "William Shakespeare",
+        source: "Hamlet",
+    },
+    Quote {
+        text: "The only thing we have to fear is fear itself.",
+        author: "Franklin D. Roosevelt",
+        source: "First Inaugural Address",
+    },
+    Quote {
+        text: "Four score and seven years ago our fathers brought forth on this continent, a new nation, conceived in Liberty, and dedicated to the proposition that all men are created equal.",
+        author: "Abraham Lincoln",
+        source: "Gettysburg Address",
+    },
+    Quote {
+        text: "I have not failed. I've just found 10,000 ways that won't work.",
+        author: "Thomas Edison",
+        source: "Attributed",
+    },
+    Quote {
+        text: "There is nothing either good or bad, but thinking makes it so.",
+        author: "William Shakespeare",
+        source: "Hamlet",
+    },
+    Quote {
+        text: "It is impossible to enjoy idling thoroughly unless one has plenty of work to do.",
+        author: "Jerome K. Jerome",
+        source: "Idle Thoughts of an Idle Fellow",
+    },
+    Quote {
+        text: "Whenever you find yourself on the side of the majority, it is time to pause and reflect.",
+        author: "Mark Twain",
+        source: "Notebook",
+    },
+    Quote {
+        text: "The man who does not read has no advantage over the man who cannot read.",
+        author: "Mark Twain",
+        source: "Attributed",
+    },
+    Quote {
+        text: "We are all in the gutter, but some of us are looking at the stars.",
+        author: "Oscar Wilde",
+        source: "Lady Windermere's Fan",
+    },
+    Quote {
+        text: "Not all those who wander are lost.",
+        author: "J. R. R. Tolkien",
+        source: "The Fellowship of the Ring",
+    },
+];
@@ -0,0 +1,387 @@
+use lazy_static::lazy_static;
+use unicode_width::UnicodeWidthChar;
+
+use crate::session::TypingSession;
+use crate::words::{Status, Word};
+
+/// Current line and the ones after it, so user can read ahead
+pub const LINES_TO_DISPLAY: usize = 3;
+/// Row of status line when typed letters are hidden
+pub const STATUS_LINE_ROW: usize = LINES_TO_DISPLAY + 1;
+
+/// Where letter actually typed instead of a wrong one is shown
+#[derive(clap::ValueEnum, serde::Deserialize, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum TypedLetters {
+    Hidden,
+    /// In a row above the text
+    Over,
+    /// In a row under the text
+    Under,
+}
+
+impl TypedLetters {
+    fn get_rows_per_line(&self) -> usize {
+        match self {
+            TypedLetters::Hidden => 1,
+            TypedLetters::Over | TypedLetters::Under => 2,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ColorsPair {
+    Text = 1,
+    Correct = 2,
+    Wrong = 3,
+}
+
+impl ColorsPair {
+    pub const ALL: [ColorsPair; 3] = [ColorsPair::Text, ColorsPair::Correct, ColorsPair::Wrong];
+}
+
+/// Screen the session is drawn on
+pub trait Renderer {
+    fn clear(&mut self);
+    fn move_to(&mut self, row: usize, column: usize);
+    fn set_color(&mut self, color: ColorsPair);
+    fn add_str(&mut self, text: &str);
+    fn refresh(&mut self);
+}
+
+/// Shows current line at the top, upcoming lines below it and status line under them.
+/// With typed letters shown every line takes two rows.
+pub fn show_session(
+    renderer: &mut impl Renderer,
+    session: &mut TypingSession,
+    status_line: &str,
+    typed_letters: TypedLetters,
+) {
+    renderer.clear();
+    let margin = session.get_words().get_margin();
+    let rows_per_line = typed_letters.get_rows_per_line();
+    let text_row = (typed_letters == TypedLetters::Over) as usize;
+    let lines = session.get_lines_to_display(LINES_TO_DISPLAY);
+    for (i, line) in lines.into_iter().enumerate() {
+        let row = i * rows_per_line;
+        let words = &session.get_words().data[line];
+        renderer.move_to(row + text_row, 0);
+        show_margin(renderer, margin);
+        for word in words {
+            show_word(renderer, word);
+        }
+        show_margin(renderer, margin);
+        if typed_letters != TypedLetters::Hidden {
+            renderer.move_to(row + 1 - text_row, margin);
+            for word in words {
+                show_typed_letters(renderer, word);
+            }
+        }
+    }
+    renderer.move_to(LINES_TO_DISPLAY * rows_per_line + 1, margin);
+    renderer.add_str(status_line);
+
+    renderer.move_to(text_row, session.get_cursor().get_x());
+    renderer.refresh();
+}
+
+fn show_word(renderer: &mut impl Renderer, word: &Word) {
+    for letter in &word.letters {
+        match &letter.status {
+            Status::Unmark => {
+                renderer.add_str(&letter.current_letter.to_string()[..]);
+            }
+            Status::Correct => {
+                show_correct_letter(renderer, letter.current_letter);
+            }
+            Status::Wrong | Status::Extra | Status::Missed => {
+                show_wrong_letter(renderer, letter.current_letter);
+            }
+        }
+    }
+}
+
+/// Letters typed instead of wrong ones, at the same columns as the wrong letters
+fn show_typed_letters(renderer: &mut impl Renderer, word: &Word) {
+    for letter in &word.letters {
+        let typed = match (&letter.status, letter.typed) {
+            // Space would be invisible
+            (Status::Wrong, Some(' ')) => Some('␣'),
+            (Status::Wrong, typed) => typed,
+            _ => None,
+        };
+        // Letter wider than the wrong one would move the rest of the row
+        match typed.filter(|it| it.width().unwrap_or(0) <= letter.width()) {
+            Some(typed) => {
+                renderer.set_color(ColorsPair::Wrong);
+                renderer.add_str(&typed.to_string());
+                renderer.set_color(ColorsPair::Text);
+                show_margin(renderer, letter.width() - typed.width().unwrap_or(0));
+            }
+            None => show_margin(renderer, letter.width()),
+        }
+    }
+}
+
+fn show_correct_letter(renderer: &mut impl Renderer, word: char) {
+    renderer.set_color(ColorsPair::Correct);
+    renderer.add_str(&word.to_string()[..]);
+    renderer.set_color(ColorsPair::Text);
+}
+
+fn show_wrong_letter(renderer: &mut impl Renderer, word: char) {
+    renderer.set_color(ColorsPair::Wrong);
+    renderer.add_str(&word.to_string()[..]);
+    renderer.set_color(ColorsPair::Text);
+}
+
+lazy_static! {
+    static ref MARGIN: String = String::from(" ").repeat(1024);
+}
+
+fn show_margin(renderer: &mut impl Renderer, margin: usize) {
+    // NOTE: Program will crash if margin is bigger then 1024 but I is not a realistic scenario.
+    renderer.add_str(&MARGIN[0..margin]);
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Cell {
+    pub letter: char,
+    pub color: ColorsPair,
+}
+
+/// In-memory screen which records every character with its color, for tests and snapshots
+#[derive(Debug)]
+pub struct VirtualScreen {
+    width: usize,
+    cells: Vec<Vec<Cell>>,
+    row: usize,
+    column: usize,
+    color: ColorsPair,
+}
+
+const EMPTY_CELL: Cell = Cell {
+    letter: ' ',
+    color: ColorsPair::Text,
+};
+
+impl VirtualScreen {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            cells: vec![vec![EMPTY_CELL; width]; height],
+            row: 0,
+            column: 0,
+            color: ColorsPair::Text,
+        }
+    }
+    pub fn get_cell(&self, row: usize, column: usize) -> Cell {
+        self.cells[row][column]
+    }
+    /// Cursor position as `(row, column)`
+    pub fn get_cursor(&self) -> (usize, usize) {
+        (self.row, self.column)
+    }
+    /// Text of the row without trailing spaces
+    pub fn get_row_text(&self, row: usize) -> String {
+        let text = self.cells[row]
+            .iter()
+            .map(|it| it.letter)
+            .filter(|it| *it != '\0')
+            .collect::<String>();
+        text.trim_end().to_string()
+    }
+    /// Colors of the row, one character per cell: `.` text, `g` correct, `r` wrong.
+    /// Trailing text cells are skipped.
+    pub fn get_row_colors(&self, row: usize) -> String {
+        let colors = self.cells[row]
+            .iter()
+            .map(|it| match it.color {
+                ColorsPair::Text => '.',
+                ColorsPair::Correct => 'g',
+                ColorsPair::Wrong => 'r',
+            })
+            .collect::<String>();
+        colors.trim_end_matches('.').to_string()
+    }
+}
+
+impl Renderer for VirtualScreen {
+    fn clear(&mut self) {
+        for row in &mut self.cells {
+            row.fill(EMPTY_CELL);
+        }
+        self.row = 0;
+        self.column = 0;
+    }
+    fn move_to(&mut self, row: usize, column: usize) {
+        self.row = row;
+        self.column = column;
+    }
+    fn set_color(&mut self, color: ColorsPair) {
+        self.color = color;
+    }
+    fn add_str(&mut self, text: &str) {
+        for letter in text.chars() {
+            let width = letter.width().unwrap_or(0);
+            if self.row >= self.cells.len() || self.column + width > self.width {
+                // Text which doesn't fit in the row is dropped
+                continue;
+            }
+            self.cells[self.row][self.column] = Cell {
+                letter,
+                color: self.color,
+            };
+            // Second column of a wide character stays empty
+            for column in self.column + 1..self.column + width {
+                self.cells[self.row][column] = Cell {
+                    letter: '\0',
+                    color: self.color,
+                };
+            }
+            self.column += width;
+        }
+    }
+    fn refresh(&mut self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::event_handler::MAX_EXTRA_LETTERS;
+    use crate::session::{Key, TestLimit};
+    use crate::words::get_fixed_words;
+
+    fn render(words: &[&str], terminal_width: usize, typed: &str) -> VirtualScreen {
+        render_with(words, terminal_width, typed, TypedLetters::Hidden)
+    }
+
+    fn render_with(
+        words: &[&str],
+        terminal_width: usize,
+        typed: &str,
+        typed_letters: TypedLetters,
+    ) -> VirtualScreen {
+        let words = words.iter().map(|it| it.to_string()).collect::<Vec<String>>();
+        let words = get_fixed_words(&words, terminal_width, 2, true);
+        let mut session = TypingSession::new(words, TestLimit::Words(100), terminal_width);
+        for c in typed.chars() {
+            let key = if c == '<' { Key::Backspace } else { Key::Char(c) };
+            session.on_key(key, Duration::ZERO);
+        }
+        let mut screen = VirtualScreen::new(terminal_width, 8);
+        show_session(&mut screen, &mut session, "status", typed_letters);
+        screen
+    }
+
+    #[test]
+    fn shows_typed_letters_in_colors() {
+        let screen = render(&["abc", "def"], 40, "axc d");
+        assert_eq!(screen.get_row_text(0), "  abc def");
+        assert_eq!(screen.get_row_colors(0), "..grggg");
+        assert_eq!(screen.get_cursor(), (0, 7));
+    }
+
+    #[test]
+    fn shows_extra_letters_after_word() {
+        let screen = render(&["ab", "cd"], 40, "abxy");
+        assert_eq!(screen.get_row_text(0), "  abxy cd");
+        assert_eq!(screen.get_row_colors(0), "..ggrr");
+        assert_eq!(screen.get_cursor(), (0, 6));
+    }
+
+    #[test]
+    fn shows_upcoming_lines_and_status_line() {
+        let screen = render(&["abc", "def", "ghi", "jkl", "mno"], 14, "abc ");
+        assert_eq!(screen.get_row_text(0), "  abc def");
+        assert_eq!(screen.get_row_text(1), "  ghi jkl");
+        assert_eq!(screen.get_row_text(2), "  mno");
+        assert_eq!(screen.get_row_text(3), "");
+        assert_eq!(screen.get_row_text(STATUS_LINE_ROW), "  status");
+    }
+
+    #[test]
+    fn scrolls_when_line_is_completed_and_back_on_backspace() {
+        let words = ["abc", "def", "ghi", "jkl", "mno"];
+        let screen = render(&words, 14, "abc def ");
+        assert_eq!(screen.get_row_text(0), "  ghi jkl");
+        assert_eq!(screen.get_row_text(1), "  mno");
+        assert_eq!(screen.get_cursor(), (0, 2));
+
+        let screen = render(&words, 14, "abc def <");
+        assert_eq!(screen.get_row_text(0), "  abc def");
+        assert_eq!(screen.get_row_colors(0), "..ggggggg");
+        assert_eq!(screen.get_cursor(), (0, 9));
+    }
+
+    #[test]
+    fn redraws_lines_after_resize() {
+        let words = ["abc", "def", "ghi", "jkl", "mno"].map(String::from);
+        let words = get_fixed_words(&words, 14, 2, true);
+        let mut session = TypingSession::new(words, TestLimit::Words(5), 14);
+        for c in "abc def g".chars() {
+            session.on_key(Key::Char(c), Duration::ZERO);
+        }
+        session.resize(30);
+        let mut screen = VirtualScreen::new(30, 6);
+        show_session(&mut screen, &mut session, "status", TypedLetters::Hidden);
+        assert_eq!(screen.get_row_text(0), "  abc def ghi jkl mno");
+        assert_eq!(screen.get_row_colors(0), "..ggggggggg");
+        assert_eq!(screen.get_cursor(), (0, 11));
+    }
+
+    #[test]
+    fn wide_letters_take_two_columns() {
+        let screen = render(&["日本", "ab"], 40, "日");
+        assert_eq!(screen.get_row_text(0), "  日本 ab");
+        assert_eq!(screen.get_row_colors(0), "..gg");
+        assert_eq!(screen.get_cursor(), (0, 4));
+    }
+
+    #[test]
+    fn shows_typed_letters_over_or_under_wrong_ones() {
+        let words = ["abc", "def", "ghi", "jkl"];
+        let screen = render_with(&words, 14, "xbc d ", TypedLetters::Over);
+        assert_eq!(screen.get_row_text(0), "  x    ␣");
+        assert_eq!(screen.get_row_colors(0), "..r....r");
+        assert_eq!(screen.get_row_text(1), "  abc def");
+        assert_eq!(screen.get_row_text(3), "  ghi jkl");
+        assert_eq!(screen.get_row_text(7), "  status");
+        assert_eq!(screen.get_cursor(), (1, 8));
+
+        let screen = render_with(&words, 14, "xbc d ", TypedLetters::Under);
+        assert_eq!(screen.get_row_text(0), "  abc def");
+        assert_eq!(screen.get_row_text(1), "  x    ␣");
+        assert_eq!(screen.get_cursor(), (0, 8));
+    }
+
+    #[test]
+    fn word_with_extra_letters_moves_next_words_to_next_line() {
+        let words = ["klmno", "fghij", "abc"];
+        let screen = render(&words, 20, "klmno");
+        assert_eq!(screen.get_row_text(0), "  klmno fghij");
+
+        let screen = render(&words, 20, &format!("klmno{}", "x".repeat(12)));
+        assert_eq!(screen.get_row_text(0), "  klmnoxxxxxxxxxx");
+        assert_eq!(screen.get_row_text(1), "  fghij abc");
+        assert_eq!(screen.get_cursor(), (0, 2 + 5 + MAX_EXTRA_LETTERS));
+
+        let screen = render(&words, 20, &format!("klmno{}<<<<<<<<<<", "x".repeat(10)));
+        assert_eq!(screen.get_row_text(0), "  klmno fghij");
+        assert_eq!(screen.get_cursor(), (0, 7));
+
+        // Word that is not the first one in the line is moved to the next line with the cursor
+        let words = ["ab", "klmno", "fghij"];
+        let screen = render(&words, 20, &format!("ab klmno{}", "x".repeat(8)));
+        assert_eq!(screen.get_row_text(0), "  klmnoxxxxxxxx");
+        assert_eq!(screen.get_row_text(1), "  fghij");
+        assert_eq!(screen.get_cursor(), (0, 2 + 5 + 8));
+
+        let screen = render(&words, 20, &format!("ab klmno{}{}", "x".repeat(8), "<".repeat(15)));
+        assert_eq!(screen.get_row_text(0), "  ab klmno fghij");
+        assert_eq!(screen.get_row_colors(0), "..g");
+        assert_eq!(screen.get_cursor(), (0, 3));
+    }
+}
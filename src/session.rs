@@ -0,0 +1,330 @@
+use std::ops::Range;
+use std::time::Duration;
+
+use crate::cursor_position::CursorPosition;
+use crate::event_handler::{on_backspace, on_keypress, on_word_skip, undo_word_skip};
+use crate::stats::{Mistake, TestStats};
+use crate::words::Status::{Missed, Unmark, Wrong};
+use crate::words::Words;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Key {
+    Char(char),
+    Backspace,
+}
+
+/// When the test ends
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum TestLimit {
+    /// Given time after the first keystroke
+    Time(Duration),
+    /// Once given number of words is completed
+    Words(usize),
+}
+
+/// Single typing test. Timestamps passed to it can be measured from any fixed moment,
+/// e.g. creation of the session, as long as it's the same for all calls.
+#[derive(Debug)]
+pub struct TypingSession {
+    words: Words,
+    cursor: CursorPosition,
+    limit: TestLimit,
+    terminal_width: usize,
+    started_at: Option<Duration>,
+    finished_at: Option<Duration>,
+    correctly_pressed_letters: usize,
+    all_letter_pressed: usize,
+    extra_letters_pressed: usize,
+    skip_on_space: bool,
+}
+
+impl TypingSession {
+    pub fn new(words: Words, limit: TestLimit, terminal_width: usize) -> Self {
+        Self {
+            cursor: CursorPosition::new(words.get_margin()),
+            words,
+            limit,
+            terminal_width,
+            started_at: None,
+            finished_at: None,
+            correctly_pressed_letters: 0,
+            all_letter_pressed: 0,
+            extra_letters_pressed: 0,
+            skip_on_space: false,
+        }
+    }
+    /// When enabled space submits the word even if it isn't fully typed
+    pub fn set_skip_on_space(&mut self, skip_on_space: bool) {
+        self.skip_on_space = skip_on_space;
+    }
+    pub fn get_words(&self) -> &Words {
+        &self.words
+    }
+    pub fn get_cursor(&self) -> &CursorPosition {
+        &self.cursor
+    }
+    pub fn get_limit(&self) -> TestLimit {
+        self.limit
+    }
+    pub fn did_start_typing(&self) -> bool {
+        self.started_at.is_some()
+    }
+    pub fn get_completed_words(&self) -> usize {
+        self.words.data.iter().filter(|it| it.completed).count()
+    }
+    /// Moment when time-bound test ends, `None` before typing starts or in words mode
+    pub fn get_deadline(&self) -> Option<Duration> {
+        match (self.limit, self.started_at) {
+            (TestLimit::Time(timeframe), Some(started_at)) => Some(started_at + timeframe),
+            _ => None,
+        }
+    }
+    pub fn is_finished(&self, now: Duration) -> bool {
+        self.finished_at.is_some() || self.get_deadline().is_some_and(|it| now >= it)
+    }
+    pub fn get_stats(&self, now: Duration) -> TestStats {
+        let duration = match (self.started_at, self.finished_at, self.get_deadline()) {
+            (Some(started_at), Some(finished_at), _) => finished_at.saturating_sub(started_at),
+            // Time spent after the deadline doesn't count, those keystrokes are discarded
+            (Some(started_at), None, Some(deadline)) => deadline.min(now).saturating_sub(started_at),
+            (Some(started_at), None, None) => now.saturating_sub(started_at),
+            (None, _, _) => Duration::ZERO,
+        };
+        let missed_characters = self
+            .words
+            .data
+            .iter()
+            .flat_map(|it| &it.letters)
+            .filter(|it| it.status == Missed)
+            .count();
+        TestStats {
+            extra_characters: self.extra_letters_pressed,
+            missed_characters,
+            ..TestStats::new(self.correctly_pressed_letters, self.all_letter_pressed, duration)
+        }
+    }
+    /// Wrong letters left in the text, the most common pairs of expected and typed letter first
+    pub fn get_mistakes(&self) -> Vec<Mistake> {
+        let mut mistakes: Vec<Mistake> = vec![];
+        let letters = self.words.data.iter().flat_map(|it| &it.letters);
+        for letter in letters.filter(|it| it.status == Wrong) {
+            let typed = letter.typed.unwrap();
+            let expected = letter.current_letter;
+            match mistakes.iter_mut().find(|it| it.expected == expected && it.typed == typed) {
+                Some(mistake) => mistake.count += 1,
+                None => mistakes.push(Mistake {
+                    expected,
+                    typed,
+                    count: 1,
+                }),
+            }
+        }
+        mistakes.sort_by_key(|it| std::cmp::Reverse(it.count));
+        mistakes
+    }
+    /// Returns `false` if key was ignored because the test has already ended
+    pub fn on_key(&mut self, key: Key, now: Duration) -> bool {
+        if self.is_finished(now) {
+            return false;
+        }
+        // Start measuring time on first keypress
+        if self.started_at.is_none() {
+            self.started_at = Some(now);
+        }
+
+        // Words are completed in order, so first not completed word is the one being typed
+        let i = self.get_completed_words();
+        if !self.words.has_word(i) {
+            return true;
+        }
+        match key {
+            Key::Backspace => {
+                // If on_backspace return false we have to modify word before him
+                if !on_backspace(&mut self.words.data[i], &mut self.cursor) && i != 0 {
+                    let previous = &mut self.words.data[i - 1];
+                    let space = previous.letters.last_mut().unwrap();
+                    space.status = Unmark;
+                    space.typed = None;
+                    previous.completed = false;
+                    self.cursor.move_left(space.width());
+                    // Go back to line of previous word before moving over skipped letters
+                    self.words.update_current_line(&mut self.cursor, self.terminal_width);
+                    undo_word_skip(&mut self.words.data[i - 1], &mut self.cursor);
+                }
+            }
+            Key::Char(' ') if self.skip_on_space => on_word_skip(
+                &mut self.words.data[i],
+                &mut self.cursor,
+                &mut self.correctly_pressed_letters,
+                &mut self.all_letter_pressed,
+            ),
+            Key::Char(c) => on_keypress(
+                &mut self.words.data[i],
+                c,
+                &mut self.cursor,
+                &mut self.correctly_pressed_letters,
+                &mut self.all_letter_pressed,
+                &mut self.extra_letters_pressed,
+            ),
+        }
+        self.words.update_current_line(&mut self.cursor, self.terminal_width);
+
+        if let TestLimit::Words(words_count) = self.limit {
+            if self.get_completed_words() >= words_count {
+                self.finished_at = Some(now);
+            }
+        }
+        true
+    }
+    pub fn resize(&mut self, terminal_width: usize) {
+        self.terminal_width = terminal_width;
+        self.words.relayout(&mut self.cursor, terminal_width);
+    }
+    /// Ranges of `data` in current line and `count - 1` lines after it
+    pub fn get_lines_to_display(&mut self, count: usize) -> Vec<Range<usize>> {
+        self.words.get_lines(count, self.terminal_width)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event_handler::MAX_EXTRA_LETTERS;
+    use crate::quotes::Quote;
+    use crate::words::{get_quote_words, shuffle_and_get_words};
+    use crate::words_generator::SamplingMode;
+
+    fn session(text: &'static str, limit: TestLimit) -> TypingSession {
+        let quote = Quote {
+            text,
+            author: "",
+            source: "",
+        };
+        TypingSession::new(get_quote_words(&quote, 80, 4), limit, 80)
+    }
+
+    fn type_text(session: &mut TypingSession, text: &str, now: Duration) {
+        for c in text.chars() {
+            session.on_key(Key::Char(c), now);
+        }
+    }
+
+    #[test]
+    fn counts_correct_and_wrong_letters() {
+        let mut session = session("abc def", TestLimit::Words(2));
+        type_text(&mut session, "abx d", Duration::from_secs(1));
+
+        let stats = session.get_stats(Duration::from_secs(2));
+        assert_eq!(stats.correct_characters, 4);
+        assert_eq!(stats.all_characters, 5);
+        assert_eq!(session.get_completed_words(), 1);
+        assert_eq!(session.get_cursor().get_x(), 4 + 5);
+    }
+
+    #[test]
+    fn letters_typed_instead_of_space_are_extra() {
+        let mut session = session("ab cd", TestLimit::Words(2));
+        type_text(&mut session, "abxy", Duration::ZERO);
+        session.on_key(Key::Backspace, Duration::ZERO);
+        type_text(&mut session, &"z".repeat(20), Duration::ZERO);
+        type_text(&mut session, " ", Duration::ZERO);
+
+        let word = &session.get_words().data[0];
+        assert_eq!(word.size(), 2 + MAX_EXTRA_LETTERS + 1);
+        assert!(word.completed);
+        let stats = session.get_stats(Duration::ZERO);
+        assert_eq!(stats.extra_characters, 2 + 20);
+        assert_eq!(stats.all_characters, 2 + stats.extra_characters + 1);
+        assert_eq!(stats.correct_characters, 3);
+    }
+
+    #[test]
+    fn lists_mistakes_left_in_text() {
+        let mut session = session("abc abd aaaa", TestLimit::Words(3));
+        type_text(&mut session, "xbc xbd ayy", Duration::ZERO);
+        session.on_key(Key::Backspace, Duration::ZERO);
+
+        let mistakes = session.get_mistakes();
+        assert_eq!(mistakes.len(), 2);
+        assert_eq!((mistakes[0].expected, mistakes[0].typed, mistakes[0].count), ('a', 'x', 2));
+        assert_eq!((mistakes[1].expected, mistakes[1].typed, mistakes[1].count), ('a', 'y', 1));
+        assert_eq!(session.get_words().data[2].letters[2].typed, None);
+    }
+
+    #[test]
+    fn backspace_goes_back_to_previous_word() {
+        let mut session = session("abc def", TestLimit::Words(2));
+        type_text(&mut session, "abc ", Duration::ZERO);
+        session.on_key(Key::Backspace, Duration::ZERO);
+
+        assert_eq!(session.get_completed_words(), 0);
+        assert_eq!(session.get_words().data[0].letters[3].status, Unmark);
+        assert_eq!(session.get_cursor().get_x(), 4 + 3);
+    }
+
+    #[test]
+    fn words_limit_ends_test_on_last_word() {
+        let mut session = session("abc def", TestLimit::Words(2));
+        type_text(&mut session, "abc def", Duration::from_secs(3));
+
+        assert!(session.is_finished(Duration::from_secs(3)));
+        assert!(!session.on_key(Key::Char('x'), Duration::from_secs(4)));
+        assert_eq!(session.get_stats(Duration::from_secs(10)).duration, Duration::ZERO);
+    }
+
+    #[test]
+    fn last_generated_word_has_no_trailing_space() {
+        let words_list = vec![String::from("ab")];
+        let words = shuffle_and_get_words(&words_list, 80, 4, Some(2), SamplingMode::Uniform, None);
+        let mut session = TypingSession::new(words, TestLimit::Words(2), 80);
+        type_text(&mut session, "ab ab", Duration::from_secs(1));
+
+        assert!(session.is_finished(Duration::from_secs(1)));
+        assert_eq!(session.get_stats(Duration::from_secs(1)).all_characters, 5);
+    }
+
+    #[test]
+    fn resize_moves_cursor_to_the_same_letter() {
+        let quote = Quote {
+            text: "abc def ghi jkl mno",
+            author: "",
+            source: "",
+        };
+        let mut session =
+            TypingSession::new(get_quote_words(&quote, 20, 4), TestLimit::Words(5), 20);
+        type_text(&mut session, "abc def gh", Duration::ZERO);
+        assert_eq!(session.get_cursor().get_line_position(), 1);
+        assert_eq!(session.get_cursor().get_x(), 4 + 2);
+
+        session.resize(40);
+        assert_eq!(session.get_cursor().get_line_position(), 0);
+        assert_eq!(session.get_cursor().get_x(), 4 + 10);
+
+        // Every word gets its own line
+        session.resize(14);
+        assert_eq!(session.get_cursor().get_line_position(), 2);
+        assert_eq!(session.get_cursor().get_x(), 4 + 2);
+
+        for _ in 0..3 {
+            session.on_key(Key::Backspace, Duration::ZERO);
+        }
+        assert_eq!(session.get_completed_words(), 1);
+        assert_eq!(session.get_cursor().get_line_position(), 1);
+        assert_eq!(session.get_cursor().get_x(), 4 + 3);
+        session.on_key(Key::Backspace, Duration::ZERO);
+        assert_eq!(session.get_cursor().get_x(), 4 + 2);
+        assert_eq!(session.get_lines_to_display(2), vec![1..2, 2..3]);
+    }
+
+    #[test]
+    fn time_limit_discards_keys_after_deadline() {
+        let mut session = session("abc def", TestLimit::Time(Duration::from_secs(60)));
+        session.on_key(Key::Char('a'), Duration::from_secs(10));
+        session.on_key(Key::Char('b'), Duration::from_secs(69));
+
+        assert!(!session.on_key(Key::Char('c'), Duration::from_secs(70)));
+        let stats = session.get_stats(Duration::from_secs(100));
+        assert_eq!(stats.all_characters, 2);
+        assert_eq!(stats.duration, Duration::from_secs(60));
+    }
+}
@@ -0,0 +1,195 @@
+pub static WORDS: [&str; 193] = [
+    "de",
+    "la",
+    "que",
+    "el",
+    "en",
+    "y",
+    "a",
+    "los",
+    "se",
+    "del",
+    "las",
+    "un",
+    "por",
+    "con",
+    "no",
+    "una",
+    "su",
+    "para",
+    "es",
+    "al",
+    "lo",
+    "como",
+    "más",
+    "pero",
+    "sus",
+    "le",
+    "ya",
+    "o",
+    "este",
+    "sí",
+    "porque",
+    "esta",
+    "entre",
+    "cuando",
+    "muy",
+    "sin",
+    "sobre",
+    "también",
+    "me",
+    "hasta",
+    "hay",
+    "donde",
+    "quien",
+    "desde",
+    "todo",
+    "nos",
+    "durante",
+    "todos",
+    "uno",
+    "les",
+    "ni",
+    "contra",
+    "otros",
+    "ese",
+    "eso",
+    "ante",
+    "ellos",
+    "e",
+    "esto",
+    "mí",
+    "antes",
+    "algunos",
+    "qué",
+    "unos",
+    "yo",
+    "otro",
+    "otras",
+    "otra",
+    "él",
+    "tanto",
+    "esa",
+    "estos",
+    "mucho",
+    "quienes",
+    "nada",
+    "muchos",
+    "cual",
+    "poco",
+    "ella",
+    "estar",
+    "estas",
+    "algunas",
+    "algo",
+    "nosotros",
+    "mi",
+    "mis",
+    "tú",
+    "te",
+    "ti",
+    "tu",
+    "tus",
+    "ellas",
+    "nosotras",
+    "vosotros",
+    "os",
+    "mío",
+    "mía",
+    "tuyo",
+    "suyo",
+    "nuestro",
+    "ser",
+    "hacer",
+    "tener",
+    "decir",
+    "ir",
+    "ver",
+    "dar",
+    "saber",
+    "querer",
+    "llegar",
+    "pasar",
+    "deber",
+    "poner",
+    "parecer",
+    "quedar",
+    "creer",
+    "hablar",
+    "llevar",
+    "dejar",
+    "seguir",
+    "encontrar",
+    "llamar",
+    "venir",
+    "pensar",
+    "salir",
+    "volver",
+    "tomar",
+    "conocer",
+    "vivir",
+    "sentir",
+    "tratar",
+    "mirar",
+    "contar",
+    "empezar",
+    "esperar",
+    "buscar",
+    "existir",
+    "entrar",
+    "trabajar",
+    "escribir",
+    "perder",
+    "producir",
+    "ocurrir",
+    "entender",
+    "pedir",
+    "recibir",
+    "recordar",
+    "terminar",
+    "permitir",
+    "aparecer",
+    "conseguir",
+    "comenzar",
+    "servir",
+    "sacar",
+    "necesitar",
+    "mantener",
+    "resultar",
+    "leer",
+    "caer",
+    "cambiar",
+    "presentar",
+    "crear",
+    "abrir",
+    "considerar",
+    "oír",
+    "acabar",
+    "tiempo",
+    "año",
+    "día",
+    "vez",
+    "casa",
+    "mundo",
+    "vida",
+    "hombre",
+    "parte",
+    "país",
+    "forma",
+    "caso",
+    "momento",
+    "lugar",
+    "persona",
+    "trabajo",
+    "mujer",
+    "gobierno",
+    "punto",
+    "agua",
+    "noche",
+    "ciudad",
+    "padre",
+    "madre",
+    "hijo",
+    "hora",
+    "nombre",
+];
@@ -0,0 +1,101 @@
+use std::time::Duration;
+
+/// Standard definition of a word used for WPM, regardless of actual word lengths
+pub const CHARACTERS_PER_WORD: f64 = 5.0;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TestStats {
+    pub correct_characters: usize,
+    pub all_characters: usize,
+    /// Letters typed past the end of words, they are also counted in `all_characters`
+    pub extra_characters: usize,
+    /// Letters skipped by submitting words early, they aren't keystrokes so they are counted
+    /// only here
+    pub missed_characters: usize,
+    pub duration: Duration,
+}
+
+impl TestStats {
+    pub fn new(correct_characters: usize, all_characters: usize, duration: Duration) -> Self {
+        Self {
+            correct_characters,
+            all_characters,
+            extra_characters: 0,
+            missed_characters: 0,
+            duration,
+        }
+    }
+    /// Words per minute counting only correctly typed characters
+    pub fn net_wpm(&self) -> f64 {
+        wpm(self.correct_characters, self.duration)
+    }
+    /// Words per minute counting every keystroke, correct or not
+    pub fn raw_wpm(&self) -> f64 {
+        wpm(self.all_characters, self.duration)
+    }
+    /// Percentage of correct keystrokes
+    pub fn accuracy(&self) -> f64 {
+        if self.all_characters == 0 {
+            return 0.0;
+        }
+        self.correct_characters as f64 / self.all_characters as f64 * 100.0
+    }
+}
+
+/// Letter that was typed as a different one
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mistake {
+    pub expected: char,
+    pub typed: char,
+    pub count: usize,
+}
+
+fn wpm(characters: usize, duration: Duration) -> f64 {
+    let minutes = duration.as_secs_f64() / 60.0;
+    if minutes == 0.0 {
+        return 0.0;
+    }
+    characters as f64 / CHARACTERS_PER_WORD / minutes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wpm_uses_five_characters_per_word() {
+        let stats = TestStats::new(250, 250, Duration::from_secs(60));
+        assert_eq!(stats.net_wpm(), 50.0);
+        assert_eq!(stats.raw_wpm(), 50.0);
+    }
+
+    #[test]
+    fn wpm_uses_actual_duration() {
+        let stats = TestStats::new(100, 100, Duration::from_secs(30));
+        assert_eq!(stats.net_wpm(), 40.0);
+
+        let stats = TestStats::new(100, 100, Duration::from_millis(90_000));
+        assert!((stats.net_wpm() - 13.333).abs() < 0.001);
+    }
+
+    #[test]
+    fn net_wpm_counts_only_correct_characters() {
+        let stats = TestStats::new(200, 300, Duration::from_secs(60));
+        assert_eq!(stats.net_wpm(), 40.0);
+        assert_eq!(stats.raw_wpm(), 60.0);
+    }
+
+    #[test]
+    fn accuracy_is_percentage_of_correct_characters() {
+        let stats = TestStats::new(90, 120, Duration::from_secs(60));
+        assert_eq!(stats.accuracy(), 75.0);
+    }
+
+    #[test]
+    fn empty_test_has_zero_stats() {
+        let stats = TestStats::new(0, 0, Duration::ZERO);
+        assert_eq!(stats.net_wpm(), 0.0);
+        assert_eq!(stats.raw_wpm(), 0.0);
+        assert_eq!(stats.accuracy(), 0.0);
+    }
+}
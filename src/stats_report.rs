@@ -0,0 +1,262 @@
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+
+use crate::history::{load_history, HistoryEntry};
+
+#[derive(clap::Args)]
+pub struct StatsArgs {
+    /// Only include tests taken on or after this date (YYYY-MM-DD)
+    #[arg(long)]
+    from: Option<NaiveDate>,
+
+    /// Only include tests taken on or before this date (YYYY-MM-DD)
+    #[arg(long)]
+    to: Option<NaiveDate>,
+
+    /// Only include tests with this word list (name, file path or `quote`)
+    #[arg(short, long)]
+    words_list: Option<String>,
+}
+
+pub fn print_stats(args: &StatsArgs) {
+    let history = match load_history() {
+        Ok(history) => history,
+        Err(err) => {
+            eprintln!("Can't read history: {}", err);
+            std::process::exit(1);
+        }
+    };
+    let entries = filter_entries(history, args);
+    if entries.is_empty() {
+        println!("No tests found");
+        return;
+    }
+
+    print_personal_bests(&entries);
+    println!();
+    print_averages(&entries);
+    println!();
+    print_daily_trend(&entries);
+}
+
+/// Entries matching date range and word list, oldest first
+fn filter_entries(history: Vec<HistoryEntry>, args: &StatsArgs) -> Vec<HistoryEntry> {
+    let mut entries = history
+        .into_iter()
+        .filter(|it| {
+            let date = it.timestamp.date_naive();
+            args.from.is_none_or(|from| date >= from)
+                && args.to.is_none_or(|to| date <= to)
+                && args.words_list.as_ref().is_none_or(|words_list| &it.words_list == words_list)
+        })
+        .collect::<Vec<HistoryEntry>>();
+    entries.sort_by_key(|it| it.timestamp);
+    entries
+}
+
+/// Fastest test of every configuration, the earliest one wins a tie
+fn get_personal_bests(entries: &[HistoryEntry]) -> BTreeMap<String, &HistoryEntry> {
+    let mut bests: BTreeMap<String, &HistoryEntry> = BTreeMap::new();
+    for entry in entries {
+        let best = bests.entry(entry.configuration()).or_insert(entry);
+        if entry.wpm > best.wpm {
+            *best = entry;
+        }
+    }
+    bests
+}
+
+#[derive(PartialEq, Debug)]
+struct Average {
+    /// How many of the latest tests were asked for
+    last: usize,
+    tests: usize,
+    wpm: f64,
+    accuracy: f64,
+}
+
+fn get_averages(entries: &[HistoryEntry]) -> Vec<Average> {
+    [10, 100]
+        .into_iter()
+        .map(|last| {
+            let recent = &entries[entries.len().saturating_sub(last)..];
+            Average {
+                last,
+                tests: recent.len(),
+                wpm: average(recent.iter().map(|it| it.wpm)),
+                accuracy: average(recent.iter().map(|it| it.accuracy)),
+            }
+        })
+        .collect()
+}
+
+#[derive(PartialEq, Debug)]
+struct DayStats {
+    date: NaiveDate,
+    tests: usize,
+    average_wpm: f64,
+    best_wpm: f64,
+    accuracy: f64,
+}
+
+fn get_daily_trend(entries: &[HistoryEntry]) -> Vec<DayStats> {
+    let mut days: BTreeMap<NaiveDate, Vec<&HistoryEntry>> = BTreeMap::new();
+    for entry in entries {
+        days.entry(entry.timestamp.date_naive()).or_default().push(entry);
+    }
+    days.into_iter()
+        .map(|(date, day)| DayStats {
+            date,
+            tests: day.len(),
+            average_wpm: average(day.iter().map(|it| it.wpm)),
+            best_wpm: day.iter().map(|it| it.wpm).fold(0.0, f64::max),
+            accuracy: average(day.iter().map(|it| it.accuracy)),
+        })
+        .collect()
+}
+
+fn print_personal_bests(entries: &[HistoryEntry]) {
+    println!("Personal bests");
+    println!("{:<40} {:>6} {:>9}  DATE", "CONFIGURATION", "WPM", "ACCURACY");
+    for (configuration, best) in get_personal_bests(entries) {
+        println!(
+            "{:<40} {:>6.1} {:>8.1}%  {}",
+            configuration,
+            best.wpm,
+            best.accuracy,
+            best.timestamp.format("%Y-%m-%d")
+        );
+    }
+}
+
+fn print_averages(entries: &[HistoryEntry]) {
+    println!("Averages");
+    println!("{:<8} {:>6} {:>6} {:>9}", "LAST", "TESTS", "WPM", "ACCURACY");
+    for it in get_averages(entries) {
+        println!("{:<8} {:>6} {:>6.1} {:>8.1}%", it.last, it.tests, it.wpm, it.accuracy);
+    }
+}
+
+fn print_daily_trend(entries: &[HistoryEntry]) {
+    println!("Daily trend");
+    println!("{:<12} {:>6} {:>8} {:>9} {:>9}", "DATE", "TESTS", "AVG WPM", "BEST WPM", "ACCURACY");
+    for day in get_daily_trend(entries) {
+        println!(
+            "{:<12} {:>6} {:>8.1} {:>9.1} {:>8.1}%",
+            day.date.format("%Y-%m-%d").to_string(),
+            day.tests,
+            day.average_wpm,
+            day.best_wpm,
+            day.accuracy
+        );
+    }
+}
+
+fn average(values: impl Iterator<Item = f64>) -> f64 {
+    let (sum, count) = values.fold((0.0, 0), |(sum, count), it| (sum + it, count + 1));
+    if count == 0 {
+        return 0.0;
+    }
+    sum / count as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Local, TimeZone};
+
+    use super::*;
+    use crate::history::TestMode;
+
+    fn entry(day: u32, hour: u32, words_list: &str, timeframe: u64, wpm: f64) -> HistoryEntry {
+        HistoryEntry {
+            timestamp: Local.with_ymd_and_hms(2026, 10, day, hour, 0, 0).unwrap(),
+            mode: TestMode::Time,
+            timeframe: Some(timeframe),
+            words_count: None,
+            words_list: words_list.to_string(),
+            min_word_length: 0,
+            max_word_length: 1000,
+            wpm,
+            raw_wpm: wpm,
+            accuracy: wpm / 2.0,
+            characters: 100,
+            correct_characters: 100,
+            extra_characters: 0,
+            missed_characters: 0,
+            seed: None,
+            daily: false,
+        }
+    }
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 10, day).unwrap()
+    }
+
+    #[test]
+    fn filters_by_date_range_and_words_list() {
+        let history = vec![
+            entry(3, 12, "english", 60, 50.0),
+            entry(1, 12, "english", 60, 40.0),
+            entry(2, 12, "german", 60, 30.0),
+            entry(2, 8, "english", 60, 45.0),
+        ];
+        let args = StatsArgs {
+            from: Some(date(2)),
+            to: Some(date(3)),
+            words_list: Some(String::from("english")),
+        };
+        let wpms = filter_entries(history, &args).iter().map(|it| it.wpm).collect::<Vec<f64>>();
+        assert_eq!(wpms, [45.0, 50.0]);
+    }
+
+    #[test]
+    fn keeps_best_of_every_configuration() {
+        let entries = [
+            entry(1, 12, "english", 60, 40.0),
+            entry(2, 12, "english", 60, 55.0),
+            entry(3, 12, "english", 60, 55.0),
+            entry(3, 13, "english", 30, 60.0),
+            entry(3, 14, "german", 60, 35.0),
+        ];
+        let bests = get_personal_bests(&entries)
+            .into_iter()
+            .map(|(configuration, best)| (configuration, best.wpm, best.timestamp.date_naive()))
+            .collect::<Vec<(String, f64, NaiveDate)>>();
+        assert_eq!(
+            bests,
+            [
+                (String::from("english, 30s"), 60.0, date(3)),
+                (String::from("english, 60s"), 55.0, date(2)),
+                (String::from("german, 60s"), 35.0, date(3)),
+            ]
+        );
+    }
+
+    #[test]
+    fn averages_latest_tests() {
+        let entries = (0..12)
+            .map(|it| entry(1, it, "english", 60, 10.0 * it as f64))
+            .collect::<Vec<HistoryEntry>>();
+        let averages = get_averages(&entries);
+        assert_eq!(averages[0], Average { last: 10, tests: 10, wpm: 65.0, accuracy: 32.5 });
+        assert_eq!(averages[1], Average { last: 100, tests: 12, wpm: 55.0, accuracy: 27.5 });
+    }
+
+    #[test]
+    fn groups_trend_by_day() {
+        let entries = [
+            entry(1, 8, "english", 60, 40.0),
+            entry(1, 20, "german", 30, 60.0),
+            entry(4, 12, "english", 60, 50.0),
+        ];
+        let day = |day, tests, average_wpm, best_wpm| DayStats {
+            date: date(day),
+            tests,
+            average_wpm,
+            best_wpm,
+            accuracy: average_wpm / 2.0,
+        };
+        assert_eq!(get_daily_trend(&entries), [day(1, 2, 50.0, 60.0), day(4, 1, 50.0, 50.0)]);
+    }
+}
@@ -0,0 +1,275 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+use crate::render::ColorsPair;
+
+/// Levels of the 6x6x6 color cube in 256-color terminals
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+const BASIC_COLORS: [&str; 8] =
+    ["black", "red", "green", "yellow", "blue", "magenta", "cyan", "white"];
+
+/// Color written as `default`, one of 8 basic color names, 256-color index or `#rrggbb`
+#[derive(Deserialize, Clone, Copy, PartialEq, Debug)]
+#[serde(try_from = "RawColor")]
+pub enum Color {
+    /// Terminal's own foreground or background
+    Default,
+    Indexed(u8),
+    Rgb(u8, u8, u8),
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawColor {
+    Index(u8),
+    Name(String),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct InvalidColor(String);
+
+impl Display for InvalidColor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid color `{}`, expected `default`, one of {}, 0-255 or #rrggbb",
+            self.0,
+            BASIC_COLORS.join(", ")
+        )
+    }
+}
+
+impl FromStr for Color {
+    type Err = InvalidColor;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let name = text.to_lowercase();
+        if name == "default" {
+            return Ok(Color::Default);
+        }
+        if let Some(index) = BASIC_COLORS.iter().position(|it| *it == name) {
+            return Ok(Color::Indexed(index as u8));
+        }
+        if let Ok(index) = name.parse::<u8>() {
+            return Ok(Color::Indexed(index));
+        }
+        let channel = |range| u8::from_str_radix(name.get(range)?, 16).ok();
+        match name.strip_prefix('#').map(str::len) {
+            Some(6) => match (channel(1..3), channel(3..5), channel(5..7)) {
+                (Some(red), Some(green), Some(blue)) => Ok(Color::Rgb(red, green, blue)),
+                _ => Err(InvalidColor(text.to_string())),
+            },
+            _ => Err(InvalidColor(text.to_string())),
+        }
+    }
+}
+
+impl TryFrom<RawColor> for Color {
+    type Error = InvalidColor;
+
+    fn try_from(value: RawColor) -> Result<Self, Self::Error> {
+        match value {
+            RawColor::Index(index) => Ok(Color::Indexed(index)),
+            RawColor::Name(name) => name.parse(),
+        }
+    }
+}
+
+/// Terminals with this many colors take any RGB color as `0xrrggbb`
+pub const DIRECT_COLORS: i32 = 1 << 24;
+
+impl Color {
+    /// Color number for a terminal with `colors` colors, `-1` is the terminal default.
+    /// Colors the terminal doesn't have are replaced by the closest one it has.
+    pub fn get_terminal_color(&self, colors: i32) -> i32 {
+        match *self {
+            Color::Default => -1,
+            // Direct color terminals still use first 8 numbers for basic colors
+            Color::Indexed(index) if index < 8 => index as i32,
+            Color::Indexed(index) if colors < DIRECT_COLORS && (index as i32) < colors => {
+                index as i32
+            }
+            _ if colors >= DIRECT_COLORS => {
+                let (red, green, blue) = self.get_rgb();
+                (red as i32) << 16 | (green as i32) << 8 | blue as i32
+            }
+            Color::Rgb(red, green, blue) if colors >= 256 => {
+                let level = |channel: u8| {
+                    (0..CUBE_LEVELS.len())
+                        .min_by_key(|it| CUBE_LEVELS[*it].abs_diff(channel))
+                        .unwrap() as i32
+                };
+                16 + 36 * level(red) + 6 * level(green) + level(blue)
+            }
+            _ => {
+                // Closest of 8 basic colors, each channel is either on or off
+                let (red, green, blue) = self.get_rgb();
+                let is_on = |channel: u8| (channel >= 128) as i32;
+                is_on(red) | is_on(green) << 1 | is_on(blue) << 2
+            }
+        }
+    }
+    /// RGB of the color in standard xterm palette, default color is treated as black
+    fn get_rgb(&self) -> (u8, u8, u8) {
+        match *self {
+            Color::Default => (0, 0, 0),
+            Color::Indexed(index) if index < 16 => {
+                let level = if index < 8 { 205 } else { 255 };
+                let channel = |bit: u8| if index & bit != 0 { level } else { 0 };
+                (channel(1), channel(2), channel(4))
+            }
+            Color::Indexed(index) if index < 232 => {
+                let index = (index - 16) as usize;
+                (
+                    CUBE_LEVELS[index / 36],
+                    CUBE_LEVELS[index / 6 % 6],
+                    CUBE_LEVELS[index % 6],
+                )
+            }
+            Color::Indexed(index) => {
+                let gray = 8 + 10 * (index - 232);
+                (gray, gray, gray)
+            }
+            Color::Rgb(red, green, blue) => (red, green, blue),
+        }
+    }
+}
+
+/// How one kind of letter looks
+#[derive(Deserialize, Clone, Copy, PartialEq, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct Style {
+    pub foreground: Color,
+    pub background: Color,
+    pub bold: bool,
+    pub underline: bool,
+    pub reverse: bool,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self {
+            foreground: Color::Default,
+            background: Color::Default,
+            bold: false,
+            underline: false,
+            reverse: false,
+        }
+    }
+}
+
+impl Style {
+    fn colored(foreground: Color, background: Color) -> Self {
+        Self {
+            foreground,
+            background,
+            ..Self::default()
+        }
+    }
+}
+
+/// Styles of letters, styles missing in a config theme are taken from the default theme
+#[derive(Deserialize, Clone, PartialEq, Debug)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct Theme {
+    /// Letters that weren't typed yet and the status line
+    pub text: Style,
+    pub correct: Style,
+    pub wrong: Style,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        let black = Color::Indexed(0);
+        Self {
+            text: Style::colored(Color::Indexed(7), black),
+            correct: Style::colored(Color::Indexed(2), black),
+            wrong: Style::colored(Color::Indexed(1), black),
+        }
+    }
+}
+
+pub const THEME_NAMES: [&str; 3] = ["default", "colorblind", "monochrome"];
+
+impl Theme {
+    pub fn get_style(&self, color: ColorsPair) -> Style {
+        match color {
+            ColorsPair::Text => self.text,
+            ColorsPair::Correct => self.correct,
+            ColorsPair::Wrong => self.wrong,
+        }
+    }
+    /// Returns built-in theme with given name
+    pub fn get_builtin(name: &str) -> Option<Self> {
+        match name {
+            "default" => Some(Self::default()),
+            // Blue and orange from Okabe-Ito palette are distinguishable with every kind of
+            // color blindness, wrong letters are also underlined so color isn't the only hint
+            "colorblind" => {
+                let blue = Color::Rgb(0x56, 0xb4, 0xe9);
+                let orange = Color::Rgb(0xe6, 0x9f, 0x00);
+                Some(Self {
+                    text: Style::default(),
+                    correct: Style::colored(blue, Color::Default),
+                    wrong: Style {
+                        underline: true,
+                        ..Style::colored(orange, Color::Default)
+                    },
+                })
+            }
+            "monochrome" => Some(Self {
+                text: Style::default(),
+                correct: Style {
+                    bold: true,
+                    ..Style::default()
+                },
+                wrong: Style {
+                    underline: true,
+                    ..Style::default()
+                },
+            }),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn colors_are_parsed() {
+        assert_eq!("default".parse(), Ok(Color::Default));
+        assert_eq!("Cyan".parse(), Ok(Color::Indexed(6)));
+        assert_eq!("208".parse(), Ok(Color::Indexed(208)));
+        assert_eq!("#56b4E9".parse(), Ok(Color::Rgb(0x56, 0xb4, 0xe9)));
+        assert!("#56b4e".parse::<Color>().is_err());
+        assert!("#ü6b4e9".parse::<Color>().is_err());
+        assert!("256".parse::<Color>().is_err());
+    }
+
+    #[test]
+    fn colors_fall_back_to_what_terminal_has() {
+        let orange = Color::Rgb(0xe6, 0x9f, 0x00);
+        assert_eq!(orange.get_terminal_color(256), 178);
+        assert_eq!(orange.get_terminal_color(8), 3);
+        assert_eq!(Color::Indexed(208).get_terminal_color(256), 208);
+        assert_eq!(Color::Indexed(208).get_terminal_color(8), 3);
+        assert_eq!(Color::Indexed(10).get_terminal_color(8), 2);
+        assert_eq!(Color::Indexed(250).get_terminal_color(8), 7);
+        assert_eq!(Color::Default.get_terminal_color(8), -1);
+        assert_eq!(orange.get_terminal_color(DIRECT_COLORS), 0xe69f00);
+        assert_eq!(Color::Indexed(2).get_terminal_color(DIRECT_COLORS), 2);
+        assert_eq!(Color::Indexed(208).get_terminal_color(DIRECT_COLORS), 0xff8700);
+    }
+
+    #[test]
+    fn missing_styles_are_taken_from_default_theme() {
+        let theme: Theme = toml::from_str("correct = { foreground = 33, bold = true }").unwrap();
+        assert_eq!(theme.correct.foreground, Color::Indexed(33));
+        assert!(theme.correct.bold);
+        assert_eq!(theme.wrong, Theme::default().wrong);
+    }
+}
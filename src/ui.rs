@@ -0,0 +1,66 @@
+use ncurses::*;
+use typestorm::render::{ColorsPair, Renderer};
+use typestorm::theme::Theme;
+
+/// Draws on `/dev/tty` instead of stdout, so the result printed to stdout can be piped
+pub fn init_ncurses(theme: &Theme) -> std::io::Result<()> {
+    // Without locale ncurses can't read or display multi-byte characters
+    setlocale(LcCategory::all, "");
+    let tty = unsafe { libc::fopen(c"/dev/tty".as_ptr(), c"r+".as_ptr()) };
+    if tty.is_null() {
+        return Err(std::io::Error::last_os_error());
+    }
+    if newterm(None, tty, tty).is_null() {
+        return Err(std::io::Error::other("unknown terminal type, check $TERM"));
+    }
+    cbreak();
+    noecho();
+    keypad(stdscr(), true);
+    if !has_colors() {
+        return Ok(());
+    }
+    start_color();
+    use_default_colors();
+    for color in ColorsPair::ALL {
+        let style = theme.get_style(color);
+        let foreground = style.foreground.get_terminal_color(COLORS());
+        let background = style.background.get_terminal_color(COLORS());
+        // Extended pairs take colors above i16, which direct color terminals use for RGB
+        init_extended_pair(color as i32, foreground, background);
+    }
+    Ok(())
+}
+
+pub struct NcursesRenderer<'a> {
+    pub theme: &'a Theme,
+}
+
+impl Renderer for NcursesRenderer<'_> {
+    fn clear(&mut self) {
+        // Unlike `clear`, `erase` lets refresh update only changed cells, so redraws don't flicker
+        erase();
+    }
+    fn move_to(&mut self, row: usize, column: usize) {
+        wmove(stdscr(), row as i32, column as i32);
+    }
+    fn set_color(&mut self, color: ColorsPair) {
+        let style = self.theme.get_style(color);
+        let mut attributes = if has_colors() { COLOR_PAIR(color as i16) } else { A_NORMAL() };
+        if style.bold {
+            attributes |= A_BOLD();
+        }
+        if style.underline {
+            attributes |= A_UNDERLINE();
+        }
+        if style.reverse {
+            attributes |= A_REVERSE();
+        }
+        attrset(attributes);
+    }
+    fn add_str(&mut self, text: &str) {
+        addstr(text);
+    }
+    fn refresh(&mut self) {
+        refresh();
+    }
+}
@@ -1,25 +1,40 @@
-use lazy_static::lazy_static;
-use ncurses::{addstr, attron, COLOR_PAIR, refresh};
-use rand::seq::SliceRandom;
+use std::ops::Range;
+
+use unicode_width::UnicodeWidthChar;
 
-use crate::{ColorsPair, WordsList};
 use crate::cursor_position::CursorPosition;
+use crate::quotes::Quote;
+use crate::words_generator::{SamplingMode, WordsGenerator};
 
 #[derive(PartialEq, Debug)]
 pub enum Status {
     Unmark,
     Correct,
     Wrong,
+    /// Letter typed where a space was expected, it isn't part of the word
+    Extra,
+    /// Letter skipped by submitting the word with a space
+    Missed,
 }
 #[derive(Debug)]
 pub struct Letter {
     pub current_letter: char,
     pub status: Status,
+    /// Character typed for this letter, `None` until it's typed
+    pub typed: Option<char>,
+}
+
+impl Letter {
+    /// Number of terminal columns the letter takes
+    pub fn width(&self) -> usize {
+        self.current_letter.width().unwrap_or(0)
+    }
 }
 
 impl Word {
+    /// Number of terminal columns the word takes
     pub fn size(&self) -> usize {
-        self.letters.iter().len()
+        self.letters.iter().map(Letter::width).sum()
     }
 }
 
@@ -38,144 +53,219 @@ pub struct Words {
     previous_end: Vec<usize>,
     line_position: usize,
     margin: usize,
+    generator: Option<WordsGenerator>,
 }
 
 impl Words {
     pub fn new(data: Vec<Word>, terminal_size: usize, margin: usize) -> Self {
-        let mut end = 0;
-        let mut it = 0;
-        for word in data.iter() {
-            if it + word.size() + 2 * margin < terminal_size {
-                it += word.size();
-                end += 1;
-            } else {
-                break;
-            }
-        }
-        Self {
+        Self::create(data, None, terminal_size, margin)
+    }
+    /// Words are taken from generator as they are needed to fill the lines
+    pub fn from_generator(generator: WordsGenerator, terminal_size: usize, margin: usize) -> Self {
+        Self::create(vec![], Some(generator), terminal_size, margin)
+    }
+    fn create(
+        data: Vec<Word>,
+        generator: Option<WordsGenerator>,
+        terminal_size: usize,
+        margin: usize,
+    ) -> Self {
+        let mut words = Self {
             data,
+            generator,
             start: 0,
-            end,
+            end: 0,
             previous_start: vec![],
             previous_end: vec![],
             line_position: 0,
             margin,
+        };
+        words.end = words.get_line_end(0, terminal_size);
+        words
+    }
+    /// Returns index one past the last word that fits in line starting at `start`.
+    /// A line always holds at least one word, even if it's wider than the terminal.
+    fn get_line_end(&mut self, start: usize, terminal_width: usize) -> usize {
+        let mut end = start;
+        let mut it = 0;
+        while self.has_word(end) {
+            let size = self.data[end].size();
+            if end == start || it + size + 2 * self.margin < terminal_width {
+                it += size;
+                end += 1;
+            } else {
+                break;
+            }
+        }
+        end
+    }
+    pub fn get_margin(&self) -> usize {
+        self.margin
+    }
+    /// Checks if there is word at `index`, pulling new words from generator if needed.
+    /// Last word of a limited generator has no trailing space, so test ends with its last letter.
+    pub fn has_word(&mut self, index: usize) -> bool {
+        while self.data.len() <= index {
+            let Some(generator) = self.generator.as_mut() else {
+                return false;
+            };
+            match generator.next_word().map(String::from) {
+                Some(word) => {
+                    let word = to_word(&word, !generator.is_exhausted());
+                    self.data.push(word);
+                }
+                None => return false,
+            }
         }
+        true
     }
-    pub fn get_words_to_display(
+    /// Moves to the previous or next line when it's needed, returns range of words in current line
+    pub fn update_current_line(
         &mut self,
         cursor: &mut CursorPosition,
         terminal_width: usize,
-    ) -> &[Word] {
+    ) -> (usize, usize) {
         if self.start != 0 && self.data.iter().filter(|it| it.completed).count() < self.start {
             cursor.go_back_to_old_line();
             self.line_position -= 1;
-            self.start = self.previous_start[self.line_position];
-            self.end = self.previous_end[self.line_position];
+            self.start = self.previous_start.pop().unwrap();
+            self.end = self.previous_end.pop().unwrap();
         }
-        if self.data.iter().filter(|it| it.completed).count() > self.end - 1 {
-            let mut it = 0;
-            self.previous_start.push(self.start);
-            self.previous_end.push(self.end);
-            self.line_position += 1;
-
-            self.start = self.end;
-            cursor.move_to_new_line();
-            for word in self.data.iter().skip(self.end) {
-                if it + word.size() + 2 * self.margin < terminal_width {
-                    it += word.size();
-                    self.end += 1;
-                } else {
-                    break;
-                }
-            }
+        if self.data.iter().filter(|it| it.completed).count() >= self.end
+            && self.has_word(self.end)
+        {
+            self.move_to_next_line(cursor, terminal_width);
+        }
+        // Extra letters change width of the word being typed, so it may no longer fit in the line
+        // or leave room for words from the next line
+        if self.get_line_end(self.start, terminal_width) != self.end {
+            self.relayout(cursor, terminal_width);
         }
-        &self.data[self.start..self.end]
+        (self.start, self.end)
     }
-    pub fn show_words(&mut self, cursor: &mut CursorPosition, terminal_width: usize) {
-        show_margin(self.margin);
+    fn move_to_next_line(&mut self, cursor: &mut CursorPosition, terminal_width: usize) {
+        self.previous_start.push(self.start);
+        self.previous_end.push(self.end);
+        self.line_position += 1;
 
-        for word in self.get_words_to_display(cursor, terminal_width) {
-            for letter in &word.letters {
-                match &letter.status {
-                    Status::Unmark => {
-                        addstr(&letter.current_letter.to_string()[..]);
-                    }
-                    Status::Correct => {
-                        show_correct_letter(letter.current_letter);
-                    }
-                    Status::Wrong => {
-                        show_wrong_letter(letter.current_letter);
-                    }
+        self.start = self.end;
+        cursor.move_to_new_line();
+        self.end = self.get_line_end(self.start, terminal_width);
+    }
+    /// Breaks words into lines again for new terminal width and puts cursor where user stopped typing,
+    /// as if everything typed so far was typed with the new width
+    pub fn relayout(&mut self, cursor: &mut CursorPosition, terminal_width: usize) {
+        let completed = self.data.iter().filter(|it| it.completed).count();
+        *cursor = CursorPosition::new(self.margin);
+        self.start = 0;
+        self.previous_start.clear();
+        self.previous_end.clear();
+        self.line_position = 0;
+        self.end = self.get_line_end(0, terminal_width);
+        loop {
+            for word in &self.data[self.start..self.end] {
+                for letter in word.letters.iter().filter(|it| it.status != Status::Unmark) {
+                    cursor.move_right(letter.width());
                 }
             }
+            if completed < self.end || !self.has_word(self.end) {
+                break;
+            }
+            self.move_to_next_line(cursor, terminal_width);
         }
-
-        show_margin(self.margin);
-        cursor.display();
-        refresh();
+    }
+    /// Ranges of words in current line and `count - 1` lines after it
+    pub fn get_lines(&mut self, count: usize, terminal_width: usize) -> Vec<Range<usize>> {
+        let mut lines = Vec::with_capacity(count);
+        lines.push(self.start..self.end);
+        for _ in 1..count {
+            let start = lines.last().unwrap().end;
+            lines.push(start..self.get_line_end(start, terminal_width));
+        }
+        lines
     }
 }
 
-pub fn shuffle_and_get_words(
-    words_list: &WordsList,
+/// Keeps only words with length in `min_word_length..max_word_length`
+pub fn filter_words(
+    words_list: &[String],
     min_word_length: usize,
     max_word_length: usize,
-    terminal_width: usize,
-    margin: usize
-) -> Words {
-    let mut words = match words_list {
-        WordsList::English => Vec::from(crate::english_words::WORDS),
-        WordsList::English1k => Vec::from(crate::english1k_words::WORDS),
-    };
-    words.shuffle(&mut rand::thread_rng());
-    let data = words
+) -> Vec<String> {
+    words_list
         .iter()
-        .filter(|it| it.len() < max_word_length && it.len() >= min_word_length)
-        .map(|it| {
-            let mut letters = it
-                .chars()
-                .map(|letter| Letter {
-                    current_letter: letter,
-                    status: Status::Unmark,
-                })
-                .collect::<Vec<Letter>>();
-            letters.push(Letter {
-                current_letter: ' ',
-                status: Status::Unmark,
-            });
-            Word {
-                completed: false,
-                letters,
-            }
+        .filter(|it| {
+            let length = it.chars().count();
+            length < max_word_length && length >= min_word_length
         })
-        .collect::<Vec<Word>>();
-    Words::new(data, terminal_width,margin)
+        .cloned()
+        .collect()
 }
 
-fn show_correct_letter(word: char) {
-    attron(COLOR_PAIR(ColorsPair::Green as i16));
-    addstr(&word.to_string()[..]);
-    attron(COLOR_PAIR(ColorsPair::White as i16));
+/// In words mode only `words_count` words are generated, otherwise they never run out
+pub fn shuffle_and_get_words(
+    words_list: &[String],
+    terminal_width: usize,
+    margin: usize,
+    words_count: Option<u64>,
+    sampling: SamplingMode,
+    seed: Option<u64>,
+) -> Words {
+    let generator = WordsGenerator::new(
+        words_list.to_vec(),
+        words_count.map(|it| it as usize),
+        sampling,
+        seed,
+    );
+    Words::from_generator(generator, terminal_width, margin)
 }
 
-fn show_wrong_letter(word: char) {
-    if word == ' ' {
-        attron(COLOR_PAIR(ColorsPair::RedSpace as i16));
-        addstr(" ");
-        attron(COLOR_PAIR(ColorsPair::White as i16));
-        return;
-    }
-    attron(COLOR_PAIR(ColorsPair::Red as i16));
-    addstr(&word.to_string()[..]);
-    attron(COLOR_PAIR(ColorsPair::White as i16));
+/// Words in exactly given order, each followed by a space. Without `last_space` the last word
+/// doesn't end with a space, like the last word of a test in words mode.
+pub fn get_fixed_words(
+    words_list: &[String],
+    terminal_width: usize,
+    margin: usize,
+    last_space: bool,
+) -> Words {
+    let data = words_list
+        .iter()
+        .enumerate()
+        .map(|(i, it)| to_word(it, last_space || i + 1 != words_list.len()))
+        .collect::<Vec<Word>>();
+    Words::new(data, terminal_width, margin)
 }
 
-lazy_static! {
-    static ref MARGIN: String = String::from(" ").repeat(1024);
+/// Splits quote into words that have to be typed in order.
+/// Last word doesn't end with a space, so the quote is finished with its last character.
+pub fn get_quote_words(quote: &Quote, terminal_width: usize, margin: usize) -> Words {
+    let words = quote.text.split_whitespace().collect::<Vec<&str>>();
+    let data = words
+        .iter()
+        .enumerate()
+        .map(|(i, it)| to_word(it, i + 1 != words.len()))
+        .collect::<Vec<Word>>();
+    Words::new(data, terminal_width, margin)
 }
 
-fn show_margin(margin: usize) {
-    // NOTE: Program will crash if margin is bigger then 1024 but I is not a realistic scenario.
-    addstr(&MARGIN[0..margin]);
+fn to_word(text: &str, with_trailing_space: bool) -> Word {
+    let mut letters = text
+        .chars()
+        .map(|letter| Letter {
+            current_letter: letter,
+            status: Status::Unmark,
+            typed: None,
+        })
+        .collect::<Vec<Letter>>();
+    if with_trailing_space {
+        letters.push(Letter {
+            current_letter: ' ',
+            status: Status::Unmark,
+            typed: None,
+        });
+    }
+    Word {
+        completed: false,
+        letters,
+    }
 }
@@ -0,0 +1,123 @@
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+
+#[derive(Debug)]
+pub enum WordsFileError {
+    Unreadable(PathBuf, std::io::Error),
+    InvalidJson(PathBuf, serde_json::Error),
+    /// Index of JSON list entry
+    InvalidWord(PathBuf, usize),
+    /// Index of line in newline-separated file
+    InvalidLine(PathBuf, usize),
+    Empty(PathBuf),
+}
+
+impl Display for WordsFileError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WordsFileError::Unreadable(path, err) => {
+                write!(f, "Can't read words file {}: {}", path.display(), err)
+            }
+            WordsFileError::InvalidJson(path, err) => {
+                write!(f, "Words file {} is not a valid JSON list of words: {}", path.display(), err)
+            }
+            WordsFileError::InvalidWord(path, index) => {
+                write!(f, "Words file {} contains whitespace in word number {}", path.display(), index + 1)
+            }
+            WordsFileError::InvalidLine(path, index) => {
+                write!(f, "Words file {} contains whitespace in word on line {}", path.display(), index + 1)
+            }
+            WordsFileError::Empty(path) => {
+                write!(f, "Words file {} doesn't contain any words", path.display())
+            }
+        }
+    }
+}
+
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum JsonWords {
+    List(Vec<String>),
+    // Same shape as the word lists used by other typing tests: { "words": [...] }
+    Object { words: Vec<String> },
+}
+
+/// Reads a newline-separated or JSON (`["a", "b"]` or `{"words": ["a", "b"]}`) words file.
+pub fn load_words_file(path: &Path) -> Result<Vec<String>, WordsFileError> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|err| WordsFileError::Unreadable(path.to_path_buf(), err))?;
+
+    let trimmed = content.trim_start();
+    let is_json = trimmed.starts_with('[') || trimmed.starts_with('{');
+    // Words are kept with their positions, so errors point at the line or entry in the file
+    let words = if is_json {
+        match serde_json::from_str(trimmed) {
+            Ok(JsonWords::List(words)) | Ok(JsonWords::Object { words }) => words,
+            Err(err) => return Err(WordsFileError::InvalidJson(path.to_path_buf(), err)),
+        }
+    } else {
+        content.lines().map(String::from).collect()
+    };
+    let words = words
+        .iter()
+        .map(|it| it.trim().to_string())
+        .enumerate()
+        .filter(|(_, it)| !it.is_empty())
+        .collect::<Vec<(usize, String)>>();
+
+    if let Some((index, _)) = words.iter().find(|(_, it)| it.contains(char::is_whitespace)) {
+        return Err(if is_json {
+            WordsFileError::InvalidWord(path.to_path_buf(), *index)
+        } else {
+            WordsFileError::InvalidLine(path.to_path_buf(), *index)
+        });
+    }
+    if words.is_empty() {
+        return Err(WordsFileError::Empty(path.to_path_buf()));
+    }
+    Ok(words.into_iter().map(|(_, it)| it).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load(name: &str, content: &str) -> Result<Vec<String>, WordsFileError> {
+        let path = std::env::temp_dir().join(format!("typestorm-{}-{}", std::process::id(), name));
+        std::fs::write(&path, content).unwrap();
+        let words = load_words_file(&path);
+        std::fs::remove_file(&path).unwrap();
+        words
+    }
+
+    #[test]
+    fn loads_newline_separated_words() {
+        let words = load("lines.txt", "  alpha\n\nbeta  \r\ngamma\n").unwrap();
+        assert_eq!(words, ["alpha", "beta", "gamma"]);
+    }
+
+    #[test]
+    fn loads_json_list_and_object() {
+        assert_eq!(load("list.json", r#"["a", " b ", ""]"#).unwrap(), ["a", "b"]);
+        assert_eq!(load("object.json", r#"{"words": ["c"]}"#).unwrap(), ["c"]);
+    }
+
+    #[test]
+    fn reports_line_of_word_with_whitespace() {
+        let err = load("invalid.txt", "alpha\n\nbeta gamma\n").unwrap_err();
+        assert!(matches!(err, WordsFileError::InvalidLine(_, 2)));
+        assert!(err.to_string().ends_with("on line 3"));
+
+        let err = load("invalid.json", r#"["a", "", "b c"]"#).unwrap_err();
+        assert!(matches!(err, WordsFileError::InvalidWord(_, 2)));
+    }
+
+    #[test]
+    fn reports_empty_unreadable_and_invalid_files() {
+        assert!(matches!(load("empty.txt", "\n  \n"), Err(WordsFileError::Empty(_))));
+        assert!(matches!(load("empty.json", "[]"), Err(WordsFileError::Empty(_))));
+        assert!(matches!(load("broken.json", r#"["a", 1]"#), Err(WordsFileError::InvalidJson(..))));
+        let missing = Path::new("/nonexistent/typestorm/words.txt");
+        assert!(matches!(load_words_file(missing), Err(WordsFileError::Unreadable(..))));
+    }
+}
@@ -0,0 +1,168 @@
+use std::collections::HashSet;
+
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+#[derive(clap::ValueEnum, serde::Serialize, serde::Deserialize, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum SamplingMode {
+    /// Every word is equally likely
+    Uniform,
+    /// Words are drawn with Zipf-like weights, assuming list is ordered from most frequent
+    Frequency,
+}
+
+/// Same seed always gives the same sequence, without seed sequence is random
+pub fn create_rng(seed: Option<u64>) -> StdRng {
+    match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    }
+}
+
+/// First `top` words of a list ordered from the most frequent word, the whole list without `top`
+pub fn take_most_frequent(words: &[String], top: Option<u64>) -> &[String] {
+    match top {
+        Some(top) => &words[..words.len().min(top as usize)],
+        None => words,
+    }
+}
+
+/// Endless source of words. In uniform mode words are drawn from a shuffled bag which is refilled
+/// once empty, so every word shows up once per round. In frequency mode word with rank `n` is
+/// drawn with weight `1 / n`. In both modes the same word never comes twice in a row.
+#[derive(Debug)]
+pub struct WordsGenerator {
+    words: Vec<String>,
+    weights: Option<WeightedIndex<f64>>,
+    bag: Vec<usize>,
+    previous: Option<usize>,
+    remaining: Option<usize>,
+    rng: StdRng,
+}
+
+impl WordsGenerator {
+    /// `limit` is the total number of words to generate, `None` means no limit.
+    /// Duplicated words are dropped, only the first, most frequent occurrence is kept.
+    pub fn new(
+        words: Vec<String>,
+        limit: Option<usize>,
+        sampling: SamplingMode,
+        seed: Option<u64>,
+    ) -> Self {
+        let mut seen = HashSet::new();
+        let words = words.into_iter().filter(|it| seen.insert(it.clone())).collect::<Vec<String>>();
+        let weights = match sampling {
+            SamplingMode::Frequency if !words.is_empty() => {
+                WeightedIndex::new((1..=words.len()).map(|rank| 1.0 / rank as f64)).ok()
+            }
+            _ => None,
+        };
+        Self {
+            words,
+            weights,
+            bag: vec![],
+            previous: None,
+            remaining: limit,
+            rng: create_rng(seed),
+        }
+    }
+    /// Whether all words allowed by the limit were generated
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining == Some(0)
+    }
+    pub fn next_word(&mut self) -> Option<&str> {
+        if self.words.is_empty() || self.remaining == Some(0) {
+            return None;
+        }
+        let index = match &self.weights {
+            Some(weights) => loop {
+                let index = weights.sample(&mut self.rng);
+                let is_repeat = self
+                    .previous
+                    .is_some_and(|previous| self.words[previous] == self.words[index]);
+                if !is_repeat || self.words.len() == 1 {
+                    break index;
+                }
+            },
+            None => {
+                if self.bag.is_empty() {
+                    self.refill_bag();
+                }
+                self.bag.pop().unwrap()
+            }
+        };
+        self.previous = Some(index);
+        if let Some(remaining) = &mut self.remaining {
+            *remaining -= 1;
+        }
+        Some(&self.words[index])
+    }
+    fn refill_bag(&mut self) {
+        self.bag = (0..self.words.len()).collect();
+        self.bag.shuffle(&mut self.rng);
+        // Words are taken from the end, so make sure new round doesn't start with the last word
+        if let Some(previous) = self.previous {
+            let last = self.bag.len() - 1;
+            if self.bag.len() > 1 && self.words[self.bag[last]] == self.words[previous] {
+                self.bag.swap(0, last);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn generate(sampling: SamplingMode, seed: Option<u64>) -> Vec<String> {
+        // Duplicates can't make the same word come twice in a row
+        let words = ["a", "b", "a", "c", "d", "d"].iter().map(|it| it.to_string()).collect();
+        let mut generator = WordsGenerator::new(words, Some(100), sampling, seed);
+        std::iter::from_fn(|| generator.next_word().map(String::from)).collect()
+    }
+
+    #[test]
+    fn same_seed_gives_same_words() {
+        for sampling in [SamplingMode::Uniform, SamplingMode::Frequency] {
+            assert_eq!(generate(sampling, Some(7)), generate(sampling, Some(7)));
+        }
+    }
+
+    #[test]
+    fn stops_after_limit_and_never_repeats_word_in_a_row() {
+        for sampling in [SamplingMode::Uniform, SamplingMode::Frequency] {
+            let words = generate(sampling, None);
+            assert_eq!(words.len(), 100);
+            assert!(words.windows(2).all(|it| it[0] != it[1]));
+            assert!(["a", "b", "c", "d"].iter().all(|word| words.contains(&word.to_string())));
+        }
+    }
+
+    #[test]
+    fn frequency_sampling_prefers_first_ranks() {
+        let words = (1..=50).map(|it| format!("w{}", it)).collect::<Vec<String>>();
+        let limit = Some(10000);
+        let mut generator = WordsGenerator::new(words, limit, SamplingMode::Frequency, Some(1));
+        let words = std::iter::from_fn(|| generator.next_word().map(String::from))
+            .collect::<Vec<String>>();
+        let count = |word: &str| words.iter().filter(|it| *it == word).count();
+        assert!(count("w1") > 10 * count("w50"), "{} vs {}", count("w1"), count("w50"));
+        assert!(count("w50") > 0);
+    }
+
+    #[test]
+    fn top_keeps_only_most_frequent_words() {
+        let words = (1..=50).map(|it| format!("w{}", it)).collect::<Vec<String>>();
+        let top = take_most_frequent(&words, Some(5)).to_vec();
+        assert_eq!(top, ["w1", "w2", "w3", "w4", "w5"]);
+        assert_eq!(take_most_frequent(&words, Some(100)).len(), 50);
+        assert_eq!(take_most_frequent(&words, None).len(), 50);
+        let mut generator = WordsGenerator::new(top, Some(1000), SamplingMode::Frequency, None);
+        while let Some(word) = generator.next_word() {
+            assert!(words[..5].iter().any(|it| it == word), "{} isn't in top 5", word);
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use clap::ValueEnum;
+
+#[derive(clap::ValueEnum, serde::Deserialize, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum WordsList {
+    English,
+    English1k,
+    German,
+    Spanish,
+    French,
+    Polish,
+    Portuguese,
+}
+
+pub struct WordsListInfo {
+    pub language: &'static str,
+    pub source: &'static str,
+    /// Whether the most frequent words come first, which `--top` and frequency sampling rely on
+    pub ordered_by_frequency: bool,
+    pub words: &'static [&'static str],
+}
+
+impl WordsList {
+    pub fn info(&self) -> WordsListInfo {
+        match self {
+            WordsList::English => WordsListInfo {
+                language: "English",
+                source: "Most common English words",
+                words: &crate::english_words::WORDS,
+                ordered_by_frequency: true,
+            },
+            WordsList::English1k => WordsListInfo {
+                language: "English",
+                source: "Around 1000 most common English words, ordered by frequency",
+                words: &crate::english1k_words::WORDS,
+                ordered_by_frequency: true,
+            },
+            WordsList::German => WordsListInfo {
+                language: "German",
+                source: "Common German words by part of speech, not ordered by frequency",
+                words: &crate::german_words::WORDS,
+                ordered_by_frequency: false,
+            },
+            WordsList::Spanish => WordsListInfo {
+                language: "Spanish",
+                source: "Common Spanish words by part of speech, not ordered by frequency",
+                words: &crate::spanish_words::WORDS,
+                ordered_by_frequency: false,
+            },
+            WordsList::French => WordsListInfo {
+                language: "French",
+                source: "Common French words by part of speech, not ordered by frequency",
+                words: &crate::french_words::WORDS,
+                ordered_by_frequency: false,
+            },
+            WordsList::Polish => WordsListInfo {
+                language: "Polish",
+                source: "Common Polish words by part of speech, not ordered by frequency",
+                words: &crate::polish_words::WORDS,
+                ordered_by_frequency: false,
+            },
+            WordsList::Portuguese => WordsListInfo {
+                language: "Portuguese",
+                source: "Common Portuguese words by part of speech, not ordered by frequency",
+                words: &crate::portuguese_words::WORDS,
+                ordered_by_frequency: false,
+            },
+        }
+    }
+}
+
+pub fn get_words_list(words_list: &WordsList) -> Vec<String> {
+    words_list.info().words.iter().map(|it| it.to_string()).collect()
+}
+
+pub fn print_words_lists() {
+    println!("{:<12} {:<12} {:>6}  SOURCE", "NAME", "LANGUAGE", "WORDS");
+    for words_list in WordsList::value_variants() {
+        let info = words_list.info();
+        println!(
+            "{:<12} {:<12} {:>6}  {}",
+            words_list.to_possible_value().unwrap().get_name(),
+            info.language,
+            info.words.len(),
+            info.source
+        );
+    }
+}